@@ -0,0 +1,108 @@
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+use stately::prelude::*;
+use stately::table::Indexed;
+
+use Event::{Back, Next, Skip, Start};
+use State::{Ready, Running};
+
+const EVENTS: usize = 1_000;
+const RUNNING: usize = 16;
+
+fn transitions(criterion: &mut Criterion) {
+    let definition = ring().build().expect("a valid state machine definition");
+    let dense = ring().build_dense().expect("a valid dense state machine definition");
+
+    let mut group = criterion.benchmark_group("transitions");
+
+    group.bench_function("state_machine", |bencher| {
+        let mut fsm = definition.create();
+
+        bencher.iter(|| run(&mut fsm));
+    });
+
+    group.bench_function("dense_state_machine", |bencher| {
+        let mut fsm = dense.create();
+
+        bencher.iter(|| run(&mut fsm));
+    });
+
+    group.finish();
+}
+
+/// Starts the state machine and transitions on a repeating sequence of events
+fn run(fsm: &mut impl FiniteStateMachine<State, Event>) {
+    fsm.reset();
+    fsm.start(Start).expect("a start event");
+
+    for event in [Next, Next, Skip, Back].into_iter().cycle().take(EVENTS) {
+        black_box(fsm.event(black_box(event)).expect("a valid transition"));
+    }
+}
+
+/// Defines a ring of running states, each one transitions to the next state, skips the
+/// next state, or transitions back to the prior state
+macro_rules! ring {
+    ($($index:literal),*) => {
+        StateMachineBuilder::new()
+            .add_start_state(Start, Ready).expect("a start state")
+                .no_triggers()
+                .only_transition_on(Next, running(0)).expect("a transition")
+            $(
+            .add_state(running($index)).expect("a running state")
+                .no_triggers()
+                .transition_on(Next, running($index + 1)).expect("a transition")
+                .transition_on(Skip, running($index + 2)).expect("a transition")
+                .final_transition_on(Back, running($index + RUNNING - 1)).expect("a transition")
+            )*
+    };
+}
+
+fn ring() -> impl BuilderState<State, Event> {
+    ring!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+}
+
+/// Gets the running state of an index around the ring
+fn running(index: usize) -> State {
+    Running(u8::try_from(index % RUNNING).expect("a running state"))
+}
+
+criterion_group!(benches, transitions);
+criterion_main!(benches);
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Event {
+    Back,
+    Next,
+    Skip,
+    Start,
+}
+
+impl Indexed for Event {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+enum State {
+    #[default]
+    Initial,
+    Ready,
+    Running(u8),
+}
+
+impl Display for State {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
@@ -1,12 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use std::rc::Rc;
 
-use crate::builder::{EndTriggerState, TriggerState};
+use crate::builder::{EndTriggerState, InitialState, TriggerState};
 use crate::builder::builder::{NodeType, StateMachineBuilder};
-use crate::builder::BuilderError::ValidationError;
+use crate::builder::BuilderError::{
+    DenseUnsupported, DuplicateEventIndex, NoPreviousState, NotExhaustive, ParallelRegions, UndefinedTransition, ValidationError
+};
 use crate::builder::Result;
+use crate::state_machine::{DenseStateMachineDefinition, Enumerable, ParallelStateMachineDefinition, Shared};
 use crate::StateMachineDefinition;
+use crate::table::Indexed;
 
 /// The final builder in the state machine builder's phased build states
 ///
@@ -17,12 +20,15 @@ use crate::StateMachineDefinition;
 ///
 /// * _`TState`_ - represents the states of a state machine
 /// * _`TEvent`_ - represents the transition events of a state machine
-pub trait BuilderState<TState, TEvent> {
+pub trait BuilderState<TState, TEvent, TPayload = ()> {
     /// Associates the next build phase for end states
-    type EndState: EndTriggerState<TState, TEvent>;
+    type EndState: EndTriggerState<TState, TEvent, TPayload>;
+
+    /// Associates the next build phase for parallel regions
+    type RegionState: InitialState<TState, TEvent, TPayload>;
 
     /// Associates the next build phase for all other states
-    type TriggerState: TriggerState<TState, TEvent>;
+    type TriggerState: TriggerState<TState, TEvent, TPayload>;
 
     /// Defines an end state
     ///
@@ -46,6 +52,72 @@ pub trait BuilderState<TState, TEvent> {
     /// [`BuildError`]: crate::builder::BuilderError
     fn add_end_state(self, state: TState) -> Result<Self::EndState, TState, TEvent>;
 
+    /// Defines a global transition on an event, from every state that is not an
+    /// end state, _i.e. to cancel from every state_
+    ///
+    /// _* a transition on the event defined by a state, or by a group transition
+    /// of the state, takes precedence over the global transition_<br>
+    /// _** the target state is validated when the state machine definition is built_
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - the event that transitions the state machine
+    /// * `state` - the state that the state machine transitions to
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase if there aren't
+    /// any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    fn add_global_transition(self, event: TEvent, state: TState) -> Result<Self, TState, TEvent>
+        where Self: Sized;
+
+    /// Defines a group transition on an event, from each state of a group of states
+    ///
+    /// _* a transition on the event defined by a state takes precedence over the
+    /// group transition, which takes precedence over a global transition_<br>
+    /// _** the group and target states are validated when the state machine
+    /// definition is built_
+    ///
+    /// # Arguments
+    ///
+    /// * `states` - the group of states that the state machine transitions from
+    /// * `event` - the event that transitions the state machine
+    /// * `state` - the state that the state machine transitions to
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase
+    #[must_use]
+    fn add_group_transition(self, states: &[TState], event: TEvent, state: TState) -> Self
+        where Self: Sized;
+
+    /// Completes the states of the current region and defines a new parallel region
+    ///
+    /// A region is an independent state machine, the regions of a state machine
+    /// are built with [`build_parallel`] and all receive the same events.
+    ///
+    /// _* the current region is validated, the same as [`build`]_
+    ///
+    /// # Results
+    ///
+    /// Returns the [`InitialState`] builder phase of the new region if there
+    /// aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    /// [`build`]: BuilderState::build
+    /// [`build_parallel`]: BuilderState::build_parallel
+    fn add_region(self) -> Result<Self::RegionState, TState, TEvent>;
+
     /// Defines a start state and it's starting transition
     ///
     /// A start state is an initial entry point into the state machine.
@@ -137,19 +209,115 @@ pub trait BuilderState<TState, TEvent> {
     ///
     /// [`BuildError`]: crate::builder::BuilderError
     /// [`StateMachineDefinition`]: StateMachineDefinition
-    fn build(self) -> Result<StateMachineDefinition<TState, TEvent>, TState, TEvent>;
+    fn build(self) -> Result<StateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent>;
+
+    /// Builds a validated instance of a [`DenseStateMachineDefinition`], which assigns
+    /// dense indices to its states, and indexes its events by their [`Indexed`] index,
+    /// so that transitions are a flat table lookup
+    ///
+    /// _* the definition is validated, the same as [`build`]_<br>
+    /// _** the index of each event must be unique, i.e. `Event::A as usize`_
+    ///
+    /// # Results
+    ///
+    /// Returns an instance of a [`DenseStateMachineDefinition`] if there
+    /// aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors, if a state defines
+    /// actions, deferred events, guarded, previous state or timed transitions, or a sub
+    /// state machine, or if two events have the same index
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    /// [`build`]: BuilderState::build
+    /// [`DenseStateMachineDefinition`]: DenseStateMachineDefinition
+    /// [`Indexed`]: crate::table::Indexed
+    fn build_dense(self) -> Result<DenseStateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent>
+        where TEvent: Indexed;
+
+    /// Builds a validated instance of a [`ParallelStateMachineDefinition`],
+    /// with a region for each region defined with [`add_region`]
+    ///
+    /// _* each region is validated, the same as [`build`]_
+    ///
+    /// # Results
+    ///
+    /// Returns an instance of a [`ParallelStateMachineDefinition`] if there
+    /// aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`add_region`]: BuilderState::add_region
+    /// [`BuildError`]: crate::builder::BuilderError
+    /// [`build`]: BuilderState::build
+    /// [`ParallelStateMachineDefinition`]: ParallelStateMachineDefinition
+    fn build_parallel(
+        self,
+    ) -> Result<ParallelStateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent>;
+
+    /// Builds a validated instance of a [`StateMachineDefinition`], which defines every
+    /// [`Enumerable`] state, and uses every [`Enumerable`] event in a transition
+    ///
+    /// _* the definition is validated, the same as [`build`]_<br>
+    /// _** the event × state matrix of the definition is its_ [`coverage`]
+    ///
+    /// # Results
+    ///
+    /// Returns an instance of a [`StateMachineDefinition`] if there
+    /// aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors, or the
+    /// [`NotExhaustive`] states and events of the definition
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    /// [`build`]: BuilderState::build
+    /// [`coverage`]: StateMachineDefinition::coverage
+    /// [`Enumerable`]: Enumerable
+    /// [`NotExhaustive`]: crate::builder::BuilderError::NotExhaustive
+    /// [`StateMachineDefinition`]: StateMachineDefinition
+    fn build_strict(self) -> Result<StateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent>
+        where TState: Enumerable,
+              TEvent: Enumerable;
 }
 
-impl<TState, TEvent> BuilderState<TState, TEvent> for StateMachineBuilder<TState, TEvent>
+impl<TState, TEvent, TPayload> BuilderState<TState, TEvent, TPayload>
+    for StateMachineBuilder<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
-          TEvent: Eq + Hash,
+          TEvent: Copy + Eq + Hash,
 {
     type EndState = Self;
+    type RegionState = Self;
     type TriggerState = Self;
 
     #[inline]
     fn add_end_state(mut self, state: TState) -> Result<Self::TriggerState, TState, TEvent> {
-        self.add_state_impl(state, NodeType::END).map(|_| self)
+        self.add_state_impl(state, NodeType::END).map(|()| self)
+    }
+
+    #[inline]
+    fn add_global_transition(mut self, event: TEvent, state: TState) -> Result<Self, TState, TEvent> {
+        self.add_global_transition_impl(event, state).map(|()| self)
+    }
+
+    #[inline]
+    fn add_group_transition(mut self, states: &[TState], event: TEvent, state: TState) -> Self {
+        self.add_group_transition_impl(states, event, state);
+
+        self
+    }
+
+    fn add_region(mut self) -> Result<Self::RegionState, TState, TEvent> {
+        let mut regions = std::mem::take(&mut self.regions);
+        let initial_state = self.initial_state;
+
+        regions.push(self.build()?);
+
+        Ok(Self { regions, ..Self::initialize_with(initial_state) })
     }
 
     #[inline]
@@ -158,40 +326,63 @@ impl<TState, TEvent> BuilderState<TState, TEvent> for StateMachineBuilder<TState
         event: TEvent,
         state: TState,
     ) -> Result<Self::TriggerState, TState, TEvent> {
-        self.add_start_state_impl(event, state).map(|_| self)
+        self.add_start_state_impl(event, state).map(|()| self)
     }
 
     fn add_start_end_state(mut self, event: TEvent, state: TState) -> Result<Self::EndState, TState, TEvent> {
-        self.add_start_end_state_impl(event, state).map(|_| self)
+        self.add_start_end_state_impl(event, state).map(|()| self)
     }
 
     #[inline]
     fn add_state(mut self, state: TState) -> Result<Self::TriggerState, TState, TEvent> {
-        self.add_state_impl(state, NodeType::STATE).map(|_| self)
+        self.add_state_impl(state, NodeType::STATE).map(|()| self)
     }
 
-    fn build(self) -> Result<StateMachineDefinition<TState, TEvent>, TState, TEvent> {
-        let undefined_states = self.transitions.iter()
-            .flat_map(|(_, states)| states.values().collect::<Vec<_>>())
+    fn build(mut self) -> Result<StateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent> {
+        if !self.regions.is_empty() {
+            return Err(ParallelRegions);
+        }
+
+        let undefined_members = self.expand_global_transitions_impl()?;
+
+        let undefined_states = self.edges()
+            .map(|(_, next)| next)
             .filter(|state| !self.states.contains(state))
-            .copied()
+            .chain(undefined_members)
             .collect::<HashSet<_>>().into_iter()
             .collect::<Vec<_>>();
 
         let unreachable = self.states.iter()
-            .filter(|state| self.transitions.iter()
-                .all(|(from, transitions)|
-                    from == *state || transitions.values().all(|next| { next != *state })
-                ))
+            .filter(|state| self.edges().all(|(from, next)| from == **state || next != **state))
             .copied()
             .collect::<Vec<_>>();
 
+        let undefined_transition = self.actions.transition.keys()
+            .find(|(state, event)| !self.defines_transition(*state, *event));
+
+        if let Some((state, event)) = undefined_transition {
+            return Err(UndefinedTransition { state: *state, event: *event });
+        }
+
+        let no_previous_state = self.edges()
+            .find(|(from, next)| *from == self.initial_state && self.previous.contains_key(next));
+
+        if let Some((_, state)) = no_previous_state {
+            return Err(NoPreviousState { state });
+        }
+
         if undefined_states.is_empty() && unreachable.is_empty() {
             Ok(StateMachineDefinition {
-                end_states: Rc::new(self.end_states),
+                actions: Shared::new(self.actions),
+                deferred: Shared::new(self.deferred),
+                end_states: Shared::new(self.end_states),
+                guards: Shared::new(self.guards),
                 initial_state: self.initial_state,
-                transitions: Rc::new(self.transitions),
-                triggers: Rc::new(self.triggers),
+                previous: Shared::new(self.previous),
+                sub_machines: Shared::new(self.sub_machines),
+                timeouts: Shared::new(self.timeouts),
+                transitions: Shared::new(self.transitions),
+                triggers: Shared::new(self.triggers),
             })
         } else {
             Err(ValidationError {
@@ -200,4 +391,72 @@ impl<TState, TEvent> BuilderState<TState, TEvent> for StateMachineBuilder<TState
             })
         }
     }
+
+    fn build_dense(mut self) -> Result<DenseStateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent>
+        where TEvent: Indexed
+    {
+        let actions = &self.actions;
+
+        let unsupported = actions.compensate.keys()
+            .chain(actions.entry.keys())
+            .chain(actions.exit.keys())
+            .chain(actions.transition.keys().map(|(state, _)| state))
+            .chain(self.deferred.keys())
+            .chain(self.guards.keys())
+            .chain(self.previous.keys())
+            .chain(self.sub_machines.keys())
+            .chain(self.timeouts.keys())
+            .next();
+
+        if let Some(state) = unsupported {
+            return Err(DenseUnsupported { state: *state });
+        }
+
+        // triggers are moved to the dense definition, they are not validated by `build`
+        let triggers = std::mem::take(&mut self.triggers);
+        let definition = self.build()?;
+        let mut indices = HashMap::new();
+
+        for event in definition.transitions.values().flat_map(HashMap::keys) {
+            if let Some(existing) = indices.insert(event.index(), *event) {
+                if existing != *event {
+                    return Err(DuplicateEventIndex { event: *event, existing, index: event.index() });
+                }
+            }
+        }
+
+        Ok(DenseStateMachineDefinition::new(
+            definition.initial_state,
+            &definition.transitions,
+            &definition.end_states,
+            triggers,
+        ))
+    }
+
+    fn build_parallel(
+        mut self,
+    ) -> Result<ParallelStateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent> {
+        let mut regions = std::mem::take(&mut self.regions);
+
+        regions.push(self.build()?);
+
+        Ok(ParallelStateMachineDefinition { regions })
+    }
+
+    fn build_strict(self) -> Result<StateMachineDefinition<TState, TEvent, TPayload>, TState, TEvent>
+        where TState: Enumerable,
+              TEvent: Enumerable
+    {
+        let definition = self.build()?;
+        let coverage = definition.coverage();
+
+        if coverage.is_exhaustive() {
+            Ok(definition)
+        } else {
+            Err(NotExhaustive {
+                missing_states: coverage.missing_states,
+                unused_events: coverage.unused_events,
+            })
+        }
+    }
 }
@@ -12,12 +12,12 @@ use crate::builder::builder::StateMachineBuilder;
 ///
 /// * _`TState`_ - represents the states of a state machine
 /// * _`TEvent`_ - represents the transition events of a state machine
-pub trait InitialState<TState, TEvent> {
+pub trait InitialState<TState, TEvent, TPayload = ()> {
     /// Associates the next build phase for end states
-    type EndState: EndTriggerState<TState, TEvent>;
+    type EndState: EndTriggerState<TState, TEvent, TPayload>;
 
     /// Associates the next build phase for start states
-    type TriggerState: TriggerState<TState, TEvent>;
+    type TriggerState: TriggerState<TState, TEvent, TPayload>;
 
     /// Defines a start state and it's starting transition
     ///
@@ -75,9 +75,10 @@ pub trait InitialState<TState, TEvent> {
     ) -> Result<Self::EndState, TState, TEvent>;
 }
 
-impl<TState, TEvent> InitialState<TState, TEvent> for StateMachineBuilder<TState, TEvent>
+impl<TState, TEvent, TPayload> InitialState<TState, TEvent, TPayload>
+    for StateMachineBuilder<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
-          TEvent: Eq + Hash,
+          TEvent: Copy + Eq + Hash,
 {
     type EndState = Self;
     type TriggerState = Self;
@@ -88,7 +89,7 @@ impl<TState, TEvent> InitialState<TState, TEvent> for StateMachineBuilder<TState
         event: TEvent,
         state: TState,
     ) -> Result<Self::TriggerState, TState, TEvent> {
-        self.add_start_state_impl(event, state).map(|_| self)
+        self.add_start_state_impl(event, state).map(|()| self)
     }
 
     #[inline]
@@ -97,6 +98,6 @@ impl<TState, TEvent> InitialState<TState, TEvent> for StateMachineBuilder<TState
         event: TEvent,
         state: TState,
     ) -> Result<Self::EndState, TState, TEvent> {
-        self.add_start_end_state_impl(event, state).map(|_| self)
+        self.add_start_end_state_impl(event, state).map(|()| self)
     }
 }
@@ -1,8 +1,10 @@
 use std::hash::Hash;
+use std::time::Duration;
 
 use crate::builder::{BuilderState, TransitionsState};
 use crate::builder::builder::StateMachineBuilder;
 use crate::builder::Result;
+use crate::Shareable;
 
 /// The initial transition builder in the state machine builder's phased
 /// build states
@@ -12,12 +14,12 @@ use crate::builder::Result;
 ///
 /// A transition, _defined by an edge_, is where an event causes one state
 /// to transition to another state.
-pub trait TransitionState<TState, TEvent> {
+pub trait TransitionState<TState, TEvent, TPayload = ()> {
     /// Associate the next build phase for states with only one transition
-    type BuilderState: BuilderState<TState, TEvent>;
+    type BuilderState: BuilderState<TState, TEvent, TPayload>;
 
     /// Associate the next build phase for states with more than one transition
-    type MultiState: TransitionsState<TState, TEvent>;
+    type MultiState: TransitionsState<TState, TEvent, TPayload>;
 
     /// Defines a transition on an event for the current state being defined
     ///
@@ -42,6 +44,31 @@ pub trait TransitionState<TState, TEvent> {
         state: TState,
     ) -> Result<Self::MultiState, TState, TEvent>;
 
+    /// Defines a guarded transition on an event for the current state being defined
+    ///
+    /// _* an event can have multiple guarded transitions, their guards are
+    /// evaluated in the order they are defined and the first permitted
+    /// transition is taken_<br>
+    /// _** an unguarded transition on the same event is taken if none of
+    /// the guards permit the transition_
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - the event that transitions the state machine
+    /// * `state` - the state that the state machine transitions to
+    /// * `guard` - the predicate that permits the transition
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionsState`] builder phase
+    #[must_use]
+    fn transition_on_if(
+        self,
+        event: TEvent,
+        state: TState,
+        guard: impl Fn(TEvent, TState) -> bool + Shareable + 'static,
+    ) -> Self::MultiState;
+
     /// Defines the only transition on an event for the current state
     /// being defined
     ///
@@ -65,11 +92,130 @@ pub trait TransitionState<TState, TEvent> {
         event: TEvent,
         state: TState,
     ) -> Result<Self::BuilderState, TState, TEvent>;
+
+    /// Defines the only transition on an event, to the previous state, for the
+    /// current state being defined
+    ///
+    /// _* see_ [`transition_to_previous_on`](TransitionState::transition_to_previous_on)
+    /// _for details on how the previous state is resolved_
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - the event that transitions the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase if there aren't
+    /// any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    fn only_transition_to_previous_on(
+        self,
+        event: TEvent,
+    ) -> Result<Self::BuilderState, TState, TEvent>;
+
+    /// Defines a transition on an event, to the previous state, for the current
+    /// state being defined
+    ///
+    /// The previous state is resolved when the state machine transitions, to the
+    /// state the state machine was in before it entered the current state
+    ///
+    /// _* the state being defined can not be entered from the initial state,
+    /// which is validated when the state machine definition is built_
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - the event that transitions the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionsState`] builder phase if there aren't
+    /// any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    fn transition_to_previous_on(self, event: TEvent) -> Result<Self::MultiState, TState, TEvent>;
+
+    /// Defines a timed transition for the current state being defined, which
+    /// transitions on an event when the state machine remains in the state for
+    /// a duration
+    ///
+    /// The state times out the duration after the state machine enters it, the
+    /// event is fired by [`tick`], as a normal transition, once the state has
+    /// timed out; _the event can also transition the state machine before then_
+    ///
+    /// _* a state can only have one timed transition_
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - the time the state machine remains in the state before it times out
+    /// * `event` - the event that transitions the state machine
+    /// * `state` - the state that the state machine transitions to
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionsState`] builder phase if there aren't
+    /// any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    /// [`tick`]: crate::FiniteStateMachine::tick
+    fn transition_after(
+        self,
+        duration: Duration,
+        event: TEvent,
+        state: TState,
+    ) -> Result<Self::MultiState, TState, TEvent>;
+
+    /// Defines a timed transition for the current state being defined, which
+    /// transitions on an event when the state machine remains in the state for
+    /// a duration
+    ///
+    /// The state times out the duration after the state machine enters it, the
+    /// event is fired by [`tick`], as a normal transition, once the state has
+    /// timed out; _the event can also transition the state machine before then_
+    ///
+    /// _* a state can only have one timed transition_
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - the time the state machine remains in the state before it times out
+    /// * `event` - the event that transitions the state machine
+    /// * `state` - the state that the state machine transitions to
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase if there aren't
+    /// any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    /// [`tick`]: crate::FiniteStateMachine::tick
+    fn only_transition_after(
+        self,
+        duration: Duration,
+        event: TEvent,
+        state: TState,
+    ) -> Result<Self::BuilderState, TState, TEvent>;
 }
 
-impl<TState, TEvent> TransitionState<TState, TEvent> for StateMachineBuilder<TState, TEvent>
+impl<TState, TEvent, TPayload> TransitionState<TState, TEvent, TPayload>
+    for StateMachineBuilder<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
-          TEvent: Eq + Hash,
+          TEvent: Copy + Eq + Hash,
 {
     type BuilderState = Self;
     type MultiState = Self;
@@ -80,7 +226,19 @@ impl<TState, TEvent> TransitionState<TState, TEvent> for StateMachineBuilder<TSt
         event: TEvent,
         state: TState,
     ) -> Result<Self::MultiState, TState, TEvent> {
-        self.add_transition_impl(self.current, event, state).map(|_| self)
+        self.add_transition_impl(self.current, event, state).map(|()| self)
+    }
+
+    #[inline]
+    fn transition_on_if(
+        mut self,
+        event: TEvent,
+        state: TState,
+        guard: impl Fn(TEvent, TState) -> bool + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.add_guarded_transition_impl(self.current, event, state, guard);
+
+        self
     }
 
     #[inline]
@@ -90,6 +248,39 @@ impl<TState, TEvent> TransitionState<TState, TEvent> for StateMachineBuilder<TSt
         state: TState,
     ) -> Result<Self::BuilderState, TState, TEvent>
     {
-        self.add_transition_impl(self.current, event, state).map(|_| self as Self)
+        self.add_transition_impl(self.current, event, state).map(|()| self as Self)
+    }
+
+    #[inline]
+    fn only_transition_to_previous_on(
+        mut self,
+        event: TEvent,
+    ) -> Result<Self::BuilderState, TState, TEvent> {
+        self.add_previous_transition_impl(self.current, event).map(|()| self)
+    }
+
+    #[inline]
+    fn transition_to_previous_on(mut self, event: TEvent) -> Result<Self::MultiState, TState, TEvent> {
+        self.add_previous_transition_impl(self.current, event).map(|()| self)
+    }
+
+    #[inline]
+    fn transition_after(
+        mut self,
+        duration: Duration,
+        event: TEvent,
+        state: TState,
+    ) -> Result<Self::MultiState, TState, TEvent> {
+        self.add_timed_transition_impl(self.current, duration, event, state).map(|()| self)
+    }
+
+    #[inline]
+    fn only_transition_after(
+        mut self,
+        duration: Duration,
+        event: TEvent,
+        state: TState,
+    ) -> Result<Self::BuilderState, TState, TEvent> {
+        self.add_timed_transition_impl(self.current, duration, event, state).map(|()| self)
     }
 }
@@ -1,7 +1,13 @@
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::hash::Hash;
 
 use crate::builder::{BuilderState, EndTriggersState};
 use crate::builder::builder::StateMachineBuilder;
+#[cfg(feature = "async")]
+use crate::Sendable;
+use crate::{Shareable, TriggerError};
+use crate::state_machine::EventQueue;
 
 /// The initial end trigger builder in the state machine builder's phased
 /// build states
@@ -9,6 +15,9 @@ use crate::builder::builder::StateMachineBuilder;
 /// The initial end trigger builder allows you to optionally define
 /// one or more triggers and ensures that no transitions are defined
 ///
+/// Entry actions can also be defined for the end state being defined,
+/// before the triggers are defined
+///
 /// A [`Trigger`] is a callback that is invoked when the state
 /// machine transitions to a new state.
 ///
@@ -17,14 +26,14 @@ use crate::builder::builder::StateMachineBuilder;
 /// state_
 ///
 /// [`Trigger`]: crate::Trigger
-pub trait EndTriggerState<TState, TEvent> {
+pub trait EndTriggerState<TState, TEvent, TPayload = ()> {
     /// Associates the next build phase when no more than one
     /// trigger is defined
-    type BuilderState: BuilderState<TState, TEvent>;
+    type BuilderState: BuilderState<TState, TEvent, TPayload>;
 
     /// Associates the next build phase when more than one
     /// trigger is defined
-    type MultiState: EndTriggersState<TState, TEvent>;
+    type MultiState: EndTriggersState<TState, TEvent, TPayload>;
 
     /// Defines a trigger for the current state being defined
     ///
@@ -36,7 +45,10 @@ pub trait EndTriggerState<TState, TEvent> {
     ///
     /// Returns the [`EndTriggersState`] builder phase
     #[must_use]
-    fn trigger(self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::MultiState;
+    fn trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::MultiState;
 
     /// Declares there are no triggers for the current state being defined
     ///
@@ -57,18 +69,206 @@ pub trait EndTriggerState<TState, TEvent> {
     ///
     /// Returns the [`BuilderState`] builder phase
     #[must_use]
-    fn only_trigger(self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::BuilderState;
+    fn only_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::BuilderState;
+
+    /// Defines a trigger, which receives the payload of the event, for the
+    /// current state being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`EndTriggersState`] builder phase
+    #[must_use]
+    fn trigger_with(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::MultiState;
+
+    /// Defines a trigger, which receives the payload of the event, and declares
+    /// there are no other trigger definitions for the current state being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase
+    #[must_use]
+    fn only_trigger_with(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::BuilderState;
+
+    /// Defines an async trigger for the current state being defined, which
+    /// is awaited by an [`AsyncFiniteStateMachine`]
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the async trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`EndTriggersState`] builder phase
+    ///
+    /// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+    #[cfg(feature = "async")]
+    #[must_use]
+    fn async_trigger<TFuture>(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::MultiState
+        where TFuture: Future<Output=()> + Sendable + 'static;
+
+    /// Defines an async trigger, which is awaited by an [`AsyncFiniteStateMachine`],
+    /// and declares there are no other trigger definitions for the current state
+    /// being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the async trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase
+    ///
+    /// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+    #[cfg(feature = "async")]
+    #[must_use]
+    fn only_async_trigger<TFuture>(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::BuilderState
+        where TFuture: Future<Output=()> + Sendable + 'static;
+
+    /// Defines a fallible trigger for the current state being defined, a
+    /// failed trigger vetoes the transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the fallible trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`EndTriggersState`] builder phase
+    #[must_use]
+    fn try_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::MultiState;
+
+    /// Defines a fallible trigger, a failed trigger vetoes the transition, and
+    /// declares there are no other trigger definitions for the current state
+    /// being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the fallible trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase
+    #[must_use]
+    fn only_try_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::BuilderState;
+
+    /// Defines a trigger, which can post follow-up events, for the current
+    /// state being defined
+    ///
+    /// _* follow-up events are processed, in order, before the event that
+    /// posted them returns_
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the queued trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`EndTriggersState`] builder phase
+    #[must_use]
+    fn queued_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::MultiState;
+
+    /// Defines a trigger, which can post follow-up events, and declares there
+    /// are no other trigger definitions for the current state being defined
+    ///
+    /// _* follow-up events are processed, in order, before the event that
+    /// posted them returns_
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the queued trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`BuilderState`] builder phase
+    #[must_use]
+    fn only_queued_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::BuilderState;
+
+    /// Defines an entry action for the current state being defined
+    ///
+    /// An entry action is invoked whenever the state machine enters the state,
+    /// after the exit and transition actions of the transition, once all the
+    /// triggers of the transition succeed
+    ///
+    /// _* unlike triggers, actions are part of the state machine definition and
+    /// can not be cleared or replaced by an instance of the state machine_
+    ///
+    /// # Arguments
+    ///
+    /// * _`action`_ - the action callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`EndTriggerState`] builder phase
+    #[must_use]
+    fn on_entry(self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self;
+
+    /// Defines a compensating action for the current state being defined
+    ///
+    /// A compensating action is invoked whenever a transition that entered the
+    /// state is undone, _i.e. to compensate for the effects of the transition_
+    ///
+    /// _* the callback provides the event of the undone transition, the state
+    /// being undone, and the state being restored_
+    ///
+    /// # Arguments
+    ///
+    /// * _`action`_ - the action callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`EndTriggerState`] builder phase
+    #[must_use]
+    fn on_undo(self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self;
 }
 
-impl<TState, TEvent> EndTriggerState<TState, TEvent> for StateMachineBuilder<TState, TEvent>
+impl<TState, TEvent, TPayload> EndTriggerState<TState, TEvent, TPayload>
+    for StateMachineBuilder<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
-          TEvent: Eq + Hash,
+          TEvent: Copy + Eq + Hash,
 {
     type BuilderState = Self;
     type MultiState = Self;
 
     #[inline]
-    fn trigger(mut self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::MultiState {
+    fn trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::MultiState {
         self.trigger_on_impl(self.current, trigger);
 
         self
@@ -80,9 +280,112 @@ impl<TState, TEvent> EndTriggerState<TState, TEvent> for StateMachineBuilder<TSt
     }
 
     #[inline]
-    fn only_trigger(mut self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::BuilderState {
+    fn only_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::BuilderState {
         self.trigger_on_impl(self.current, trigger);
 
         self
     }
+
+    #[inline]
+    fn trigger_with(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.trigger_with_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn only_trigger_with(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::BuilderState {
+        self.trigger_with_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    fn async_trigger<TFuture>(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::MultiState
+        where TFuture: Future<Output=()> + Sendable + 'static
+    {
+        self.async_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    fn only_async_trigger<TFuture>(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::BuilderState
+        where TFuture: Future<Output=()> + Sendable + 'static
+    {
+        self.async_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn try_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.try_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn only_try_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::BuilderState {
+        self.try_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn queued_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.queued_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn only_queued_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::BuilderState {
+        self.queued_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn on_entry(mut self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self {
+        self.entry_action_impl(self.current, action);
+
+        self
+    }
+
+    #[inline]
+    fn on_undo(mut self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self {
+        self.undo_action_impl(self.current, action);
+
+        self
+    }
 }
@@ -1,7 +1,14 @@
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::hash::Hash;
 
 use crate::builder::{TransitionState, TriggersState};
+use crate::builder;
 use crate::builder::builder::StateMachineBuilder;
+#[cfg(feature = "async")]
+use crate::Sendable;
+use crate::{Shareable, TriggerError};
+use crate::state_machine::{EventQueue, StateMachineDefinition};
 
 /// The initial trigger builder in the state machine builder's phased
 /// build states
@@ -9,6 +16,9 @@ use crate::builder::builder::StateMachineBuilder;
 /// The initial trigger builder allows you to optionally define
 /// one or more triggers and ensures that transitions are defined
 ///
+/// Entry, exit and transition actions can also be defined for the state
+/// being defined, before the triggers are defined
+///
 /// A [`Trigger`] is a callback that is invoked when the state
 /// machine transitions to a new state.
 ///
@@ -17,14 +27,14 @@ use crate::builder::builder::StateMachineBuilder;
 /// state_
 ///
 /// [`Trigger`]: crate::Trigger
-pub trait TriggerState<TState, TEvent> {
+pub trait TriggerState<TState, TEvent, TPayload = ()> {
     /// Associate the next build phase for states with more
     /// than one trigger
-    type MultiState: TriggersState<TState, TEvent>;
+    type MultiState: TriggersState<TState, TEvent, TPayload>;
 
     /// Associates the next build phase when no more than one
     /// trigger is defined
-    type TransitionState: TransitionState<TState, TEvent>;
+    type TransitionState: TransitionState<TState, TEvent, TPayload>;
 
     /// Defines a trigger for the current state being defined
     ///
@@ -36,7 +46,10 @@ pub trait TriggerState<TState, TEvent> {
     ///
     /// Returns the [`TriggersState`] builder phase
     #[must_use]
-    fn trigger(self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::MultiState;
+    fn trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::MultiState;
 
     /// Declares there are no triggers for the current state being defined
     ///
@@ -57,18 +70,294 @@ pub trait TriggerState<TState, TEvent> {
     ///
     /// Returns the [`TransitionState`] builder phase
     #[must_use]
-    fn only_trigger(self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::TransitionState;
+    fn only_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::TransitionState;
+
+    /// Defines a trigger, which receives the payload of the event, for the
+    /// current state being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggersState`] builder phase
+    #[must_use]
+    fn trigger_with(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::MultiState;
+
+    /// Defines a trigger, which receives the payload of the event, and declares
+    /// there are no other trigger definitions for the current state being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionState`] builder phase
+    #[must_use]
+    fn only_trigger_with(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::TransitionState;
+
+    /// Defines an async trigger for the current state being defined, which
+    /// is awaited by an [`AsyncFiniteStateMachine`]
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the async trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggersState`] builder phase
+    ///
+    /// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+    #[cfg(feature = "async")]
+    #[must_use]
+    fn async_trigger<TFuture>(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::MultiState
+        where TFuture: Future<Output=()> + Sendable + 'static;
+
+    /// Defines an async trigger, which is awaited by an [`AsyncFiniteStateMachine`],
+    /// and declares there are no other trigger definitions for the current state
+    /// being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the async trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionState`] builder phase
+    ///
+    /// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+    #[cfg(feature = "async")]
+    #[must_use]
+    fn only_async_trigger<TFuture>(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::TransitionState
+        where TFuture: Future<Output=()> + Sendable + 'static;
+
+    /// Defines a fallible trigger for the current state being defined, a
+    /// failed trigger vetoes the transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the fallible trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggersState`] builder phase
+    #[must_use]
+    fn try_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::MultiState;
+
+    /// Defines a fallible trigger, a failed trigger vetoes the transition, and
+    /// declares there are no other trigger definitions for the current state
+    /// being defined
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the fallible trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionState`] builder phase
+    #[must_use]
+    fn only_try_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::TransitionState;
+
+    /// Defines a trigger, which can post follow-up events, for the current
+    /// state being defined
+    ///
+    /// _* follow-up events are processed, in order, before the event that
+    /// posted them returns_
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the queued trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggersState`] builder phase
+    #[must_use]
+    fn queued_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::MultiState;
+
+    /// Defines a trigger, which can post follow-up events, and declares there
+    /// are no other trigger definitions for the current state being defined
+    ///
+    /// _* follow-up events are processed, in order, before the event that
+    /// posted them returns_
+    ///
+    /// # Arguments
+    ///
+    /// * _`trigger`_ - the queued trigger callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TransitionState`] builder phase
+    #[must_use]
+    fn only_queued_trigger(
+        self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::TransitionState;
+
+    /// Defines a sub state machine for the current state being defined, making
+    /// it a composite state
+    ///
+    /// Entering the composite state starts the sub state machine with its start
+    /// event; events are handled by the active sub state machine first, and any
+    /// events it can not handle fall through to the transitions of the composite
+    /// state
+    ///
+    /// # Arguments
+    ///
+    /// * _`start`_ - the event that starts the sub state machine
+    /// * _`child`_ - the definition of the sub state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggerState`] builder phase if there aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: crate::builder::BuilderError
+    fn sub_machine(
+        self,
+        start: TEvent,
+        child: StateMachineDefinition<TState, TEvent, TPayload>,
+    ) -> builder::Result<Self, TState, TEvent>
+        where Self: Sized;
+
+    /// Defers an event for the current state being defined
+    ///
+    /// An event the state can not transition on, that the state defers, is held
+    /// by the state machine and retried after each successful transition, until
+    /// a state can transition on it; a held event is discarded once the state
+    /// machine enters a state that neither transitions on, nor defers, the event
+    ///
+    /// _* a transition on the event, from the state, takes precedence over
+    /// deferring the event_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the deferred event
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggerState`] builder phase
+    #[must_use]
+    fn defer(self, event: TEvent) -> Self;
+
+    /// Defines an entry action for the current state being defined
+    ///
+    /// An entry action is invoked whenever the state machine enters the state,
+    /// after the exit and transition actions of the transition, once all the
+    /// triggers of the transition succeed
+    ///
+    /// _* unlike triggers, actions are part of the state machine definition and
+    /// can not be cleared or replaced by an instance of the state machine_
+    ///
+    /// # Arguments
+    ///
+    /// * _`action`_ - the action callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggerState`] builder phase
+    #[must_use]
+    fn on_entry(self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self;
+
+    /// Defines an exit action for the current state being defined
+    ///
+    /// An exit action is invoked whenever the state machine exits the state,
+    /// before any transition and entry actions of the transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`action`_ - the action callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggerState`] builder phase
+    #[must_use]
+    fn on_exit(self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self;
+
+    /// Defines a transition action for a transition edge, on an event, of the
+    /// current state being defined
+    ///
+    /// A transition action is invoked whenever the state machine transitions
+    /// on the event from the state, after the exit actions and before the entry
+    /// actions of the transition
+    ///
+    /// _* the transition edge must be defined for the current state being defined_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the event of the transition edge
+    /// * _`action`_ - the action callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggerState`] builder phase
+    #[must_use]
+    fn on_transition(
+        self,
+        event: TEvent,
+        action: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self;
+
+    /// Defines a compensating action for the current state being defined
+    ///
+    /// A compensating action is invoked whenever a transition that entered the
+    /// state is undone, _i.e. to compensate for the effects of the transition_
+    ///
+    /// _* the callback provides the event of the undone transition, the state
+    /// being undone, and the state being restored_
+    ///
+    /// # Arguments
+    ///
+    /// * _`action`_ - the action callback
+    ///
+    /// # Results
+    ///
+    /// Returns the [`TriggerState`] builder phase
+    #[must_use]
+    fn on_undo(self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self;
 }
 
-impl<TState, TEvent> TriggerState<TState, TEvent> for StateMachineBuilder<TState, TEvent>
+impl<TState, TEvent, TPayload> TriggerState<TState, TEvent, TPayload>
+    for StateMachineBuilder<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
-          TEvent: Eq + Hash,
+          TEvent: Copy + Eq + Hash,
 {
     type MultiState = Self;
     type TransitionState = Self;
 
     #[inline]
-    fn trigger(mut self, trigger: impl Fn(TEvent, TState, TState) + 'static) -> Self::MultiState {
+    fn trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self::MultiState {
         self.trigger_on_impl(self.current, trigger);
 
         self
@@ -82,10 +371,146 @@ impl<TState, TEvent> TriggerState<TState, TEvent> for StateMachineBuilder<TState
     #[inline]
     fn only_trigger(
         mut self,
-        trigger: impl Fn(TEvent, TState, TState) + 'static,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
     ) -> Self::TransitionState {
         self.trigger_on_impl(self.current, trigger);
 
         self
     }
+
+    #[inline]
+    fn trigger_with(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.trigger_with_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn only_trigger_with(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) -> Self::TransitionState {
+        self.trigger_with_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    fn async_trigger<TFuture>(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::MultiState
+        where TFuture: Future<Output=()> + Sendable + 'static
+    {
+        self.async_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    fn only_async_trigger<TFuture>(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) -> Self::TransitionState
+        where TFuture: Future<Output=()> + Sendable + 'static
+    {
+        self.async_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn try_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.try_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn only_try_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
+    ) -> Self::TransitionState {
+        self.try_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn queued_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::MultiState {
+        self.queued_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn only_queued_trigger(
+        mut self,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) -> Self::TransitionState {
+        self.queued_trigger_on_impl(self.current, trigger);
+
+        self
+    }
+
+    #[inline]
+    fn sub_machine(
+        mut self,
+        start: TEvent,
+        child: StateMachineDefinition<TState, TEvent, TPayload>,
+    ) -> builder::Result<Self, TState, TEvent> {
+        self.sub_machine_impl(self.current, start, child)?;
+
+        Ok(self)
+    }
+
+    #[inline]
+    fn defer(mut self, event: TEvent) -> Self {
+        self.defer_impl(self.current, event);
+
+        self
+    }
+
+    #[inline]
+    fn on_entry(mut self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self {
+        self.entry_action_impl(self.current, action);
+
+        self
+    }
+
+    #[inline]
+    fn on_exit(mut self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self {
+        self.exit_action_impl(self.current, action);
+
+        self
+    }
+
+    #[inline]
+    fn on_transition(
+        mut self,
+        event: TEvent,
+        action: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) -> Self {
+        self.transition_action_impl(self.current, event, action);
+
+        self
+    }
+
+    #[inline]
+    fn on_undo(mut self, action: impl Fn(TEvent, TState, TState) + Shareable + 'static) -> Self {
+        self.undo_action_impl(self.current, action);
+
+        self
+    }
 }
@@ -1,14 +1,23 @@
 use std::borrow::BorrowMut;
+#[cfg(feature = "async")]
+use std::future::Future;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
+use std::time::Duration;
 
 use bitflags::bitflags;
 
-use crate::{builder, Trigger};
+use crate::builder;
 use crate::builder::BuilderError::{
-    RedefinedInitialState, StateAlreadyDefined, TransitionAlreadyDefined,
+    EndStateTransitions, NotAStartEvent, RedefinedInitialState, StateAlreadyDefined, SubMachineAlreadyDefined,
+    TimeoutAlreadyDefined, TransitionAlreadyDefined, TransitionToPreviousAlreadyDefined,
 };
 use crate::builder::InitialState;
+use crate::state_machine::{edges, Actions, Callback, EventQueue, Guards, SubMachines, Triggers};
+use crate::StateMachineDefinition;
+#[cfg(feature = "async")]
+use crate::{Sendable, TriggerFuture};
+use crate::{Shareable, TriggerError};
 
 bitflags! {
     #[derive(Copy, Clone)]
@@ -25,10 +34,10 @@ bitflags! {
 /// Validation occurs in three separate phases;
 ///
 /// 1. Transitioning between state builder states to eliminate a class of validations
-/// enforced at compiletime
+///    enforced at compiletime
 /// 2. Defining different components of the state machine definition
 /// 3. Building the state machine definition; where unreachable and undefined
-/// states are detected
+///    states are detected
 ///
 /// ### Example
 ///
@@ -105,14 +114,23 @@ bitflags! {
 /// ```
 ///
 /// [`StateMachineDefinition`]: crate::state_machine::StateMachineDefinition
-pub struct StateMachineBuilder<TState, TEvent> {
+pub struct StateMachineBuilder<TState, TEvent, TPayload = ()> {
+    pub(crate) actions: Actions<TState, TEvent>,
     pub(crate) current: TState,
+    pub(crate) deferred: HashMap<TState, HashSet<TEvent>>,
     pub(crate) initial_state: TState,
     pub(crate) end_states: HashSet<TState>,
+    pub(crate) global: HashMap<TEvent, TState>,
+    pub(crate) groups: Vec<(Vec<TState>, TEvent, TState)>,
+    pub(crate) guards: Guards<TState, TEvent>,
+    pub(crate) previous: HashMap<TState, HashSet<TEvent>>,
+    pub(crate) regions: Vec<StateMachineDefinition<TState, TEvent, TPayload>>,
     pub(crate) start_states: HashSet<TState>,
     pub(crate) states: HashSet<TState>,
+    pub(crate) sub_machines: SubMachines<TState, TEvent, TPayload>,
+    pub(crate) timeouts: HashMap<TState, (Duration, TEvent)>,
     pub(crate) transitions: HashMap<TState, HashMap<TEvent, TState>>,
-    pub(crate) triggers: HashMap<TState, Vec<Trigger<TState, TEvent>>>,
+    pub(crate) triggers: Triggers<TState, TEvent, TPayload>,
 }
 
 impl<TState, TEvent> StateMachineBuilder<TState, TEvent>
@@ -128,21 +146,63 @@ impl<TState, TEvent> StateMachineBuilder<TState, TEvent>
     #[allow(clippy::new_ret_no_self)]
     #[must_use]
     pub fn new() -> impl InitialState<TState, TEvent> {
-        let initial_state = TState::default();
+        Self::initialize()
+    }
+}
 
+impl<TState, TEvent, TPayload> StateMachineBuilder<TState, TEvent, TPayload>
+    where TState: Copy + Default + Eq + Hash,
+          TEvent: Copy + Eq + Hash
+{
+    /// Create a new instance of a `StateMachineBuilder` for a state machine
+    /// whose events deliver a payload of type `TPayload` to triggers
+    ///
+    /// # Results
+    ///
+    /// Returns an [`InitialState`] to start the build process
+    ///
+    #[must_use]
+    pub fn with_payload() -> impl InitialState<TState, TEvent, TPayload> {
+        Self::initialize()
+    }
+
+    /// Initializes a new instance of a `StateMachineBuilder`
+    pub(crate) fn initialize() -> Self {
+        Self::initialize_with(TState::default())
+    }
+}
+
+impl<TState, TEvent, TPayload> StateMachineBuilder<TState, TEvent, TPayload>
+    where TState: Copy
+{
+    /// Initializes a new instance of a `StateMachineBuilder` with an initial state
+    ///
+    /// # Arguments
+    ///
+    /// * _`initial_state`_ - the initial state of the state machine
+    pub(crate) fn initialize_with(initial_state: TState) -> Self {
         Self {
+            actions: Actions::default(),
             current: initial_state,
+            deferred: HashMap::default(),
             initial_state,
             end_states: HashSet::default(),
+            global: HashMap::default(),
+            groups: Vec::new(),
+            guards: HashMap::default(),
+            previous: HashMap::default(),
+            regions: Vec::new(),
             start_states: HashSet::default(),
             states: HashSet::default(),
+            sub_machines: HashMap::default(),
+            timeouts: HashMap::default(),
             transitions: HashMap::default(),
             triggers: HashMap::default(),
         }
     }
 }
 
-impl<TState, TEvent> StateMachineBuilder<TState, TEvent>
+impl<TState, TEvent, TPayload> StateMachineBuilder<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
           TEvent: Eq + Hash
 {
@@ -282,7 +342,7 @@ impl<TState, TEvent> StateMachineBuilder<TState, TEvent>
         next: TState,
     ) -> builder::Result<(), TState, TEvent> {
         let entry = self.transitions.entry(state)
-            .or_insert_with(HashMap::new);
+            .or_default();
 
         if entry.contains_key(&event) {
             let existing = entry[&event];
@@ -290,11 +350,238 @@ impl<TState, TEvent> StateMachineBuilder<TState, TEvent>
             return Err(TransitionAlreadyDefined { event, existing });
         }
 
+        if self.previous.get(&state).is_some_and(|events| events.contains(&event)) {
+            return Err(TransitionToPreviousAlreadyDefined { event });
+        }
+
         entry.borrow_mut().insert(event, next);
 
         Ok(())
     }
 
+    /// Defines a new transition to the previous state for the current state
+    /// being defined for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions from
+    /// * _`event`_ - the event that transitions the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns `()` if there aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: builder::BuilderError
+    pub(crate) fn add_previous_transition_impl(
+        &mut self,
+        state: TState,
+        event: TEvent,
+    ) -> builder::Result<(), TState, TEvent> {
+        // a state that only transitions to the previous state still defines transitions
+        let entry = self.transitions.entry(state)
+            .or_default();
+
+        if let Some(existing) = entry.get(&event) {
+            return Err(TransitionAlreadyDefined { event, existing: *existing });
+        }
+
+        let previous = self.previous.entry(state)
+            .or_default();
+
+        if previous.contains(&event) {
+            return Err(TransitionToPreviousAlreadyDefined { event });
+        }
+
+        previous.insert(event);
+
+        Ok(())
+    }
+
+    /// Defers an event for the current state being defined for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that defers the event
+    /// * _`event`_ - the deferred event
+    ///
+    pub(crate) fn defer_impl(&mut self, state: TState, event: TEvent) {
+        self.deferred.entry(state)
+            .or_default()
+            .insert(event);
+    }
+
+    /// Gets all of the transition edges defined, including guarded transitions
+    ///
+    /// # Results
+    ///
+    /// Returns an `Iterator<Item=(TState, TState)>`, which are pairs of
+    /// (from, to) transition edges
+    pub(crate) fn edges(&self) -> impl Iterator<Item=(TState, TState)> + '_ {
+        edges(&self.transitions, &self.guards)
+    }
+
+    /// Defines a new guarded transition for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// Guarded transitions of an event are evaluated in the order they are
+    /// defined, an unguarded transition on the same event is used as a fallback
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions from
+    /// * _`event`_ - the event that transitions the state machine
+    /// * _`next`_ - the state that the state machine transitions to
+    /// * _`guard`_ - the predicate that permits the transition
+    ///
+    pub(crate) fn add_guarded_transition_impl(
+        &mut self,
+        state: TState,
+        event: TEvent,
+        next: TState,
+        guard: impl Fn(TEvent, TState) -> bool + Shareable + 'static,
+    ) {
+        // a state with only guarded transitions still requires a transitions entry
+        self.transitions.entry(state).or_default();
+
+        self.guards.entry(state)
+            .or_default()
+            .entry(event)
+            .or_default()
+            .push((next, Box::new(guard)));
+    }
+
+    /// Defines a new entry action for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state being entered
+    /// * _`action`_ - the callback to invoke on entering the state
+    ///
+    pub(crate) fn entry_action_impl(
+        &mut self,
+        state: TState,
+        action: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) {
+        self.actions.entry.entry(state)
+            .or_default()
+            .push(Box::new(action));
+    }
+
+    /// Defines a new compensating action for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state being undone
+    /// * _`action`_ - the callback to invoke on undoing a transition to the state
+    ///
+    pub(crate) fn undo_action_impl(
+        &mut self,
+        state: TState,
+        action: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) {
+        self.actions.compensate.entry(state)
+            .or_default()
+            .push(Box::new(action));
+    }
+
+    /// Defines a new exit action for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state being exited
+    /// * _`action`_ - the callback to invoke on exiting the state
+    ///
+    pub(crate) fn exit_action_impl(
+        &mut self,
+        state: TState,
+        action: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) {
+        self.actions.exit.entry(state)
+            .or_default()
+            .push(Box::new(action));
+    }
+
+    /// Defines a sub state machine for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the composite state that owns the sub state machine
+    /// * _`start`_ - the event that starts the sub state machine
+    /// * _`child`_ - the definition of the sub state machine
+    ///
+    /// # Results
+    ///
+    /// Returns `()` if there aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: builder::BuilderError
+    pub(crate) fn sub_machine_impl(
+        &mut self,
+        state: TState,
+        start: TEvent,
+        child: StateMachineDefinition<TState, TEvent, TPayload>,
+    ) -> builder::Result<(), TState, TEvent> {
+        if self.sub_machines.contains_key(&state) {
+            return Err(SubMachineAlreadyDefined { state });
+        }
+
+        if !child.transitions.get(&child.initial_state)
+            .is_some_and(|starts| starts.contains_key(&start)) {
+            return Err(NotAStartEvent { event: start });
+        }
+
+        self.sub_machines.insert(state, (start, child));
+
+        Ok(())
+    }
+
+    /// Defines a new transition action for a transition edge of the current
+    /// state being defined for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions from
+    /// * _`event`_ - the event of the transition edge
+    /// * _`action`_ - the callback to invoke on transitioning
+    ///
+    pub(crate) fn transition_action_impl(
+        &mut self,
+        state: TState,
+        event: TEvent,
+        action: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) {
+        self.actions.transition.entry((state, event))
+            .or_default()
+            .push(Box::new(action));
+    }
+
     /// Defines a new trigger for the current state being defined
     /// for the state machine
     ///
@@ -308,10 +595,245 @@ impl<TState, TEvent> StateMachineBuilder<TState, TEvent>
     pub(crate) fn trigger_on_impl(
         &mut self,
         state: TState,
-        trigger: impl Fn(TEvent, TState, TState) + 'static,
+        trigger: impl Fn(TEvent, TState, TState) + Shareable + 'static,
+    ) {
+        self.triggers.entry(state)
+            .or_default()
+            .push(Callback::Trigger(Box::new(trigger)));
+    }
+
+    /// Defines a new async trigger for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions to
+    /// * _`trigger`_ - the async callback to await on transition
+    ///
+    #[cfg(feature = "async")]
+    pub(crate) fn async_trigger_on_impl<TFuture>(
+        &mut self,
+        state: TState,
+        trigger: impl Fn(TEvent, TState, TState) -> TFuture + Shareable + 'static,
+    ) where TFuture: Future<Output=()> + Sendable + 'static {
+        self.triggers.entry(state)
+            .or_default()
+            .push(Callback::Async(Box::new(move |event, prior, next| -> TriggerFuture {
+                Box::pin(trigger(event, prior, next))
+            })));
+    }
+
+    /// Defines a new fallible trigger for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions to
+    /// * _`trigger`_ - the fallible callback to invoke on transition
+    ///
+    pub(crate) fn try_trigger_on_impl(
+        &mut self,
+        state: TState,
+        trigger: impl Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Shareable + 'static,
     ) {
         self.triggers.entry(state)
-            .or_insert_with(Vec::new)
-            .push(Box::new(trigger));
+            .or_default()
+            .push(Callback::Fallible(Box::new(trigger)));
+    }
+
+    /// Defines a new trigger, which can post follow-up events, for the current
+    /// state being defined for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions to
+    /// * _`trigger`_ - the callback to invoke on transition
+    ///
+    pub(crate) fn queued_trigger_on_impl(
+        &mut self,
+        state: TState,
+        trigger: impl Fn(TEvent, TState, TState, &mut EventQueue<TEvent, TPayload>) + Shareable + 'static,
+    ) {
+        self.triggers.entry(state)
+            .or_default()
+            .push(Callback::Queued(Box::new(trigger)));
+    }
+
+    /// Defines a new trigger, which receives the payload of the event, for
+    /// the current state being defined for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions to
+    /// * _`trigger`_ - the callback to invoke on transition
+    ///
+    pub(crate) fn trigger_with_on_impl(
+        &mut self,
+        state: TState,
+        trigger: impl Fn(TEvent, TState, TState, &TPayload) + Shareable + 'static,
+    ) {
+        self.triggers.entry(state)
+            .or_default()
+            .push(Callback::Payload(Box::new(trigger)));
+    }
+}
+
+impl<TState, TEvent, TPayload> StateMachineBuilder<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash
+{
+    /// Defines a new timed transition for the current state being defined
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state that the state machine transitions from
+    /// * _`duration`_ - the time the state machine remains in the state before it times out
+    /// * _`event`_ - the event that transitions the state machine when the state times out
+    /// * _`next`_ - the state that the state machine transitions to
+    ///
+    /// # Results
+    ///
+    /// Returns `()` if there aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: builder::BuilderError
+    pub(crate) fn add_timed_transition_impl(
+        &mut self,
+        state: TState,
+        duration: Duration,
+        event: TEvent,
+        next: TState,
+    ) -> builder::Result<(), TState, TEvent> {
+        if self.timeouts.contains_key(&state) {
+            return Err(TimeoutAlreadyDefined { state });
+        }
+
+        self.add_transition_impl(state, event, next)?;
+        self.timeouts.insert(state, (duration, event));
+
+        Ok(())
+    }
+
+    /// Defines a new global transition, from every state that is not an end
+    /// state, for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the event that transitions the state machine
+    /// * _`next`_ - the state that the state machine transitions to
+    ///
+    /// # Results
+    ///
+    /// Returns `()` if there aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if there are any validation errors
+    ///
+    /// [`BuildError`]: builder::BuilderError
+    pub(crate) fn add_global_transition_impl(
+        &mut self,
+        event: TEvent,
+        next: TState,
+    ) -> builder::Result<(), TState, TEvent> {
+        if let Some(existing) = self.global.get(&event) {
+            return Err(TransitionAlreadyDefined { event, existing: *existing });
+        }
+
+        self.global.insert(event, next);
+
+        Ok(())
+    }
+
+    /// Defines a new group transition, from each state of a group of states,
+    /// for the state machine
+    ///
+    /// _used by specific trait implementations of the state machine builder_
+    ///
+    /// # Arguments
+    ///
+    /// * _`states`_ - the group of states that the state machine transitions from
+    /// * _`event`_ - the event that transitions the state machine
+    /// * _`next`_ - the state that the state machine transitions to
+    ///
+    pub(crate) fn add_group_transition_impl(&mut self, states: &[TState], event: TEvent, next: TState) {
+        self.groups.push((states.to_vec(), event, next));
+    }
+
+    /// Expands the group and global transitions into the transitions of each state,
+    /// unless the state defines a transition on the same event; group transitions
+    /// take precedence over global transitions
+    ///
+    /// _used by the build of the state machine definition_
+    ///
+    /// # Results
+    ///
+    /// Returns the states of groups that are not defined
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuildError`] if a group includes an end state, or a state of
+    /// overlapping groups transitions to different states on the same event
+    ///
+    /// [`BuildError`]: builder::BuilderError
+    pub(crate) fn expand_global_transitions_impl(&mut self) -> builder::Result<Vec<TState>, TState, TEvent> {
+        let mut undefined = Vec::new();
+        let mut expanded = HashMap::new();
+
+        for (states, event, next) in &self.groups {
+            for state in states {
+                if !self.states.contains(state) {
+                    undefined.push(*state);
+                } else if self.end_states.contains(state) {
+                    return Err(EndStateTransitions { state: *state });
+                } else if !self.defines_transition(*state, *event) {
+                    match expanded.insert((*state, *event), *next) {
+                        Some(existing) if existing != *next =>
+                            return Err(TransitionAlreadyDefined { event: *event, existing }),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for state in self.states.difference(&self.end_states) {
+            for (event, next) in &self.global {
+                if !self.defines_transition(*state, *event) {
+                    expanded.entry((*state, *event)).or_insert(*next);
+                }
+            }
+        }
+
+        for ((state, event), next) in expanded {
+            self.transitions.entry(state)
+                .or_default()
+                .insert(event, next);
+        }
+
+        Ok(undefined)
+    }
+
+    /// Indicates if a state defines a transition on an event, including guarded
+    /// transitions and transitions to the previous state
+    pub(crate) fn defines_transition(&self, state: TState, event: TEvent) -> bool {
+        self.transitions.get(&state).is_some_and(|transitions| transitions.contains_key(&event)) ||
+            self.guards.get(&state).is_some_and(|guards| guards.contains_key(&event)) ||
+            self.previous.get(&state).is_some_and(|events| events.contains(&event))
     }
 }
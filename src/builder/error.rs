@@ -8,6 +8,94 @@ use thiserror::Error;
 /// [`StateMachineBuilder`]: crate::StateMachineBuilder
 #[derive(Error, Debug)]
 pub enum BuilderError<TState, TEvent> {
+    /// Occurs when a state machine definition built with [`build_dense`] defines actions,
+    /// deferred events, guarded, previous state or timed transitions, or sub state machines,
+    /// which a dense state machine does not support
+    ///
+    /// [`build_dense`]: crate::builder::BuilderState::build_dense
+    DenseUnsupported {
+        /// `TState` that defines an unsupported feature
+        state: TState
+    },
+
+    /// Occurs when two events of a state machine definition built with [`build_dense`]
+    /// have the same [`Indexed`] index
+    ///
+    /// [`build_dense`]: crate::builder::BuilderState::build_dense
+    /// [`Indexed`]: crate::table::Indexed
+    DuplicateEventIndex {
+        /// `TEvent` with a duplicate index
+        event: TEvent,
+
+        /// Existing `TEvent` with the same index
+        existing: TEvent,
+
+        /// The duplicate index
+        index: usize,
+    },
+
+    /// Occurs when an end state defines transitions, _i.e. an end state of a
+    /// schema, or an end state included in a group transition_
+    EndStateTransitions {
+        /// The end `TState` defining transitions
+        state: TState
+    },
+
+    /// Occurs when a state of a schema is not valid, with the index of the state
+    /// in the schema
+    InvalidStateSchema {
+        /// The index of the state in the schema
+        index: usize,
+
+        /// The error of the state
+        error: Box<Self>,
+    },
+
+    /// Occurs when a state that transitions to the previous state can be
+    /// entered from the initial state, which is not a previous state
+    NoPreviousState {
+        /// `TState` that transitions to the previous state
+        state: TState
+    },
+
+    /// Occurs when a state machine definition, _i.e. a schema_, does not define
+    /// a start state
+    NoStartState,
+
+    /// Occurs when a state, which is not an end state, does not define any
+    /// transitions, _i.e. a state of a schema_
+    NoTransitions {
+        /// The `TState` without transitions
+        state: TState
+    },
+
+    /// Occurs when a state machine definition built with [`build_strict`] leaves
+    /// out states, or does not use events, of the [`Enumerable`] states and events
+    ///
+    /// [`build_strict`]: crate::builder::BuilderState::build_strict
+    /// [`Enumerable`]: crate::state_machine::Enumerable
+    NotExhaustive {
+        /// A collection of all the `TState`s that are not defined, _i.e. forgotten states_
+        missing_states: Vec<TState>,
+
+        /// A collection of all the `TEvent`s that no transition uses
+        unused_events: Vec<TEvent>,
+    },
+
+    /// Occurs when a sub state machine is started with an event
+    /// that is not a start event of the sub state machine
+    NotAStartEvent {
+        /// `TEvent` that does not start the sub state machine
+        event: TEvent
+    },
+
+    /// Occurs when a state machine definition with parallel regions is built
+    /// with [`build`], instead of [`build_parallel`]
+    ///
+    /// [`build`]: crate::builder::BuilderState::build
+    /// [`build_parallel`]: crate::builder::BuilderState::build_parallel
+    ParallelRegions,
+
     /// Occurs when the initial state of `TState` is redefined
     ///
     /// _*_ `TState` _implements_ [`Default`]_, which is used as the initial state_
@@ -19,6 +107,18 @@ pub enum BuilderError<TState, TEvent> {
         state: TState
     },
 
+    /// Occurs when a sub state machine is redefined for a composite state
+    SubMachineAlreadyDefined {
+        /// Composite `TState` with an existing sub state machine
+        state: TState
+    },
+
+    /// Occurs when a timed transition is redefined for a state
+    TimeoutAlreadyDefined {
+        /// `TState` with an existing timed transition
+        state: TState
+    },
+
     /// Occurs when a transition on `TEvent` is defined for an existing transition
     TransitionAlreadyDefined {
         /// `TEvent` to transition on
@@ -28,6 +128,23 @@ pub enum BuilderError<TState, TEvent> {
         existing: TState,
     },
 
+    /// Occurs when a transition action is defined for a transition edge
+    /// that is not defined
+    UndefinedTransition {
+        /// `TState` the transition action transitions from
+        state: TState,
+
+        /// `TEvent` the transition action transitions on
+        event: TEvent,
+    },
+
+    /// Occurs when a transition to the previous state on `TEvent` is defined
+    /// for an existing transition
+    TransitionToPreviousAlreadyDefined {
+        /// `TEvent` to transition on
+        event: TEvent,
+    },
+
     /// Occurs when a state machine definition build fails validation
     ValidationError {
         /// A collection of all the expected `TState`s that are undefined
@@ -45,15 +162,63 @@ impl<TState, TEvent> Display for BuilderError<TState, TEvent>
 {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::DenseUnsupported { state } =>
+                fmt.write_fmt(format_args!("{state} state defines features a dense state machine does not support")),
+
+            Self::DuplicateEventIndex { event, existing, index } =>
+                fmt.write_fmt(format_args!("{event} event has the same index {index} as the {existing} event")),
+
+            Self::EndStateTransitions { state } =>
+                fmt.write_fmt(format_args!("{state} end state can not define transitions")),
+
+            Self::InvalidStateSchema { index, error } =>
+                fmt.write_fmt(format_args!("State schema at index {index} is invalid, {error}")),
+
+            Self::NoPreviousState { state } =>
+                fmt.write_fmt(format_args!("{state} state transitions to the previous state, it can not be a start state")),
+
+            Self::NoStartState =>
+                fmt.write_fmt(format_args!("A start state must be defined")),
+
+            Self::NoTransitions { state } =>
+                fmt.write_fmt(format_args!("{state} state must define transitions")),
+
+            Self::NotExhaustive { missing_states, unused_events } => {
+                let unused_events = unused_events.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+                fmt.write_fmt(format_args!(
+                    "Missing state(s) {missing_states:?}, unused event(s) [{}]",
+                    unused_events.join(", ")
+                ))
+            }
+
+            Self::NotAStartEvent { event } =>
+                fmt.write_fmt(format_args!("{event} is not a starting event of the sub state machine")),
+
+            Self::ParallelRegions =>
+                fmt.write_fmt(format_args!("Parallel regions must be built with build_parallel")),
+
             Self::RedefinedInitialState =>
                 fmt.write_fmt(format_args!("Initial state can not be explicitly defined")),
 
             Self::StateAlreadyDefined { state } =>
                 fmt.write_fmt(format_args!("{state} state has already been defined")),
 
+            Self::SubMachineAlreadyDefined { state } =>
+                fmt.write_fmt(format_args!("{state} state already has a sub state machine")),
+
+            Self::TimeoutAlreadyDefined { state } =>
+                fmt.write_fmt(format_args!("{state} state already has a timed transition")),
+
             Self::TransitionAlreadyDefined { event, existing } =>
                 fmt.write_fmt(format_args!("{event} event already transitions to {existing}")),
 
+            Self::TransitionToPreviousAlreadyDefined { event } =>
+                fmt.write_fmt(format_args!("{event} event already transitions to the previous state")),
+
+            Self::UndefinedTransition { state, event } =>
+                fmt.write_fmt(format_args!("{event} event does not transition from {state}")),
+
             Self::ValidationError { undefined_states, unreachable } => {
                 let has_unreachable = !undefined_states.is_empty();
 
@@ -65,9 +65,14 @@ pub use builder::StateMachineBuilder;
 pub use error::BuilderError;
 #[doc(inline)]
 pub use result::Result;
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use schema::{Schema, StateSchema, TransitionSchema};
 
 mod build_states;
 #[allow(clippy::module_inception)] // it's not leaky
 mod builder;
 mod error;
 mod result;
+#[cfg(feature = "serde")]
+mod schema;
@@ -0,0 +1,151 @@
+use std::hash::Hash;
+
+use serde::{Deserialize, Serialize};
+
+use crate::builder;
+use crate::builder::builder::{NodeType, StateMachineBuilder};
+use crate::builder::BuilderError::{
+    EndStateTransitions, InvalidStateSchema, NoStartState, NoTransitions, ValidationError
+};
+use crate::builder::BuilderState;
+use crate::StateMachineDefinition;
+
+/// A declarative, serializable, definition of a state machine, _i.e. loaded
+/// from a JSON, TOML or YAML file_
+///
+/// A schema is built with the same validations as the [`StateMachineBuilder`],
+/// triggers are bound afterwards by state, with [`new_triggers`]
+///
+/// _* errors of a state are reported with the index of the state in the schema_
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+///
+/// _i.e._
+///
+/// ```json
+/// {
+///   "states": [
+///     { "state": "Locked", "start": "On", "transitions": [
+///       { "event": "Push", "next": "Locked" }, { "event": "Coin", "next": "Unlocked" }
+///     ] },
+///     { "state": "Unlocked", "transitions": [
+///       { "event": "Coin", "next": "Unlocked" }, { "event": "Push", "next": "Locked" }
+///     ] }
+///   ]
+/// }
+/// ```
+///
+/// [`new_triggers`]: crate::FiniteStateMachine::new_triggers
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Schema<TState, TEvent> {
+    /// the states of the state machine
+    pub states: Vec<StateSchema<TState, TEvent>>,
+}
+
+/// A declarative, serializable, definition of a state of a state machine
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "TState: Deserialize<'de>, TEvent: Deserialize<'de>"))]
+pub struct StateSchema<TState, TEvent> {
+    /// the state being defined
+    pub state: TState,
+
+    /// the event that starts the state machine, if the state is a start state
+    #[serde(default)]
+    pub start: Option<TEvent>,
+
+    /// indicates if the state is an end state
+    #[serde(default)]
+    pub end: bool,
+
+    /// the transitions of the state, end states do not define transitions
+    #[serde(default)]
+    pub transitions: Vec<TransitionSchema<TState, TEvent>>,
+}
+
+/// A declarative, serializable, definition of a transition of a state
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransitionSchema<TState, TEvent> {
+    /// the event that transitions the state machine
+    pub event: TEvent,
+
+    /// the state that the state machine transitions to
+    pub next: TState,
+}
+
+impl<TState, TEvent> Schema<TState, TEvent>
+    where TState: Copy + Default + Eq + Hash,
+          TEvent: Copy + Eq + Hash
+{
+    /// Builds a state machine definition from the schema
+    ///
+    /// # Results
+    ///
+    /// Returns a [`StateMachineDefinition`] if there aren't any validation errors
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BuilderError`] if there are any validation errors, the errors of a
+    /// state, including transitions to undefined states, are an [`InvalidStateSchema`]
+    /// error with the index of the state
+    ///
+    /// [`BuilderError`]: builder::BuilderError
+    /// [`InvalidStateSchema`]: builder::BuilderError::InvalidStateSchema
+    pub fn build(self) -> builder::Result<StateMachineDefinition<TState, TEvent>, TState, TEvent> {
+        if self.states.iter().all(|definition| definition.start.is_none()) {
+            return Err(NoStartState);
+        }
+
+        let mut builder = StateMachineBuilder::<TState, TEvent>::initialize();
+
+        for (index, definition) in self.states.iter().enumerate() {
+            Self::add_state(&mut builder, definition)
+                .map_err(|error| InvalidStateSchema { index, error: Box::new(error) })?;
+        }
+
+        builder.build().map_err(|error| {
+            let undefined = match &error {
+                ValidationError { undefined_states, .. } => self.states.iter()
+                    .position(|definition| definition.transitions.iter()
+                        .any(|transition| undefined_states.contains(&transition.next))),
+                _ => None,
+            };
+
+            match undefined {
+                Some(index) => InvalidStateSchema { index, error: Box::new(error) },
+                None => error,
+            }
+        })
+    }
+
+    /// Adds a state of the schema, and its transitions, to the builder
+    fn add_state(
+        builder: &mut StateMachineBuilder<TState, TEvent>,
+        definition: &StateSchema<TState, TEvent>,
+    ) -> builder::Result<(), TState, TEvent> {
+        let StateSchema { state, start, end, ref transitions } = *definition;
+
+        match (start, end) {
+            (Some(event), true) => builder.add_start_end_state_impl(event, state)?,
+            (Some(event), false) => builder.add_start_state_impl(event, state)?,
+            (None, true) => builder.add_state_impl(state, NodeType::END)?,
+            (None, false) => builder.add_state_impl(state, NodeType::STATE)?,
+        }
+
+        if end && !transitions.is_empty() {
+            return Err(EndStateTransitions { state });
+        }
+
+        if !end && transitions.is_empty() {
+            return Err(NoTransitions { state });
+        }
+
+        for TransitionSchema { event, next } in transitions {
+            builder.add_transition_impl(state, *event, *next)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter::once;
+
+use crate::diagram::transitions;
+use crate::graph::Graph;
+use crate::StateMachineDefinition;
+
+/// The [DOT] identifier of the initial pseudo state
+///
+/// [DOT]: https://graphviz.org/doc/info/lang.html
+const INITIAL: &str = "[*]";
+
+impl<TState, TEvent, TPayload> StateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy
+{
+    /// Renders the state machine definition as a [Graphviz] [DOT] digraph,
+    /// labelling states and events with their [`Display`] implementations
+    ///
+    /// _* start states are bold, end states are double circles, and edges
+    /// which participate in a cycle are red; nodes and edges are sorted by
+    /// label, so the output is deterministic_
+    ///
+    /// # Results
+    ///
+    /// Returns the [DOT] digraph
+    ///
+    /// [Graphviz]: https://graphviz.org
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    #[must_use]
+    pub fn to_dot(&self) -> String
+        where TState: Display,
+              TEvent: Display
+    {
+        self.to_dot_with(ToString::to_string, ToString::to_string)
+    }
+
+    /// Renders the state machine definition as a [Graphviz] [DOT] digraph,
+    /// labelling states and events with custom formatting, _i.e. with their
+    /// [`Debug`] implementations_
+    ///
+    /// _* start states are bold, end states are double circles, and edges
+    /// which participate in a cycle are red; nodes and edges are sorted by
+    /// label, so the output is deterministic_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state_label`_ - formats the label of a state
+    /// * _`event_label`_ - formats the label of an event
+    ///
+    /// # Results
+    ///
+    /// Returns the [DOT] digraph
+    ///
+    /// [Graphviz]: https://graphviz.org
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    /// [`Debug`]: std::fmt::Debug
+    pub fn to_dot_with(
+        &self,
+        state_label: impl Fn(&TState) -> String,
+        event_label: impl Fn(&TEvent) -> String,
+    ) -> String {
+        let label = |state: &TState| if *state == self.initial_state {
+            INITIAL.to_string()
+        } else {
+            state_label(state)
+        };
+
+        let states = self.transitions.keys().chain(self.end_states.iter()).copied();
+        let mut graph = Graph::new(states.clone());
+
+        for (from, next) in self.edges() {
+            graph.add_edge(from, next);
+        }
+
+        let cyclical = graph.cyclical_edges();
+
+        let nodes = states
+            .filter(|state| *state != self.initial_state)
+            .map(|state| {
+                let start = self.is_start_state(state);
+                let end = self.end_states.contains(&state);
+
+                let attributes = match (start, end) {
+                    (true, true) => "shape = doublecircle, style = bold",
+                    (true, false) => "shape = circle, style = bold",
+                    (false, true) => "shape = doublecircle",
+                    (false, false) => "shape = circle",
+                };
+
+                (label(&state), attributes)
+            })
+            .collect::<BTreeSet<_>>();
+
+        // start transitions are listed first, then by label
+        let edges = transitions(self)
+            .map(|(from, event, next)| (
+                from != self.initial_state,
+                label(&from),
+                event_label(&event),
+                label(&next),
+                cyclical.contains(&(from, next)),
+            ))
+            .collect::<BTreeSet<_>>();
+
+        let nodes = nodes.into_iter()
+            .map(|(state, attributes)| format!("    {} [{attributes}];\n", quote(&state)));
+
+        let edges = edges.into_iter()
+            .map(|(_, from, event, next, cyclical)| format!(
+                "    {} -> {} [label = {}{}];\n",
+                quote(&from),
+                quote(&next),
+                quote(&event),
+                if cyclical { ", color = red" } else { "" }
+            ));
+
+        once(String::from("digraph {\n"))
+            .chain(once(format!("    {} [shape = point];\n", quote(INITIAL))))
+            .chain(nodes)
+            .chain(edges)
+            .chain(once(String::from("}\n")))
+            .collect()
+    }
+
+    /// Checks if a state is a start state, _i.e. reached from the initial state_
+    fn is_start_state(&self, state: TState) -> bool {
+        self.transitions.get(&self.initial_state)
+            .is_some_and(|starts| starts.values().any(|start| *start == state))
+    }
+}
+
+/// Quotes a [DOT] identifier, escaping any quotes
+///
+/// [DOT]: https://graphviz.org/doc/info/lang.html
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('"', "\\\""))
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter::once;
+
+use crate::diagram::transitions;
+use crate::StateMachineDefinition;
+
+/// The [Mermaid] label of the initial and end pseudo states
+///
+/// [Mermaid]: https://mermaid.js.org/syntax/stateDiagram.html
+const PSEUDO_STATE: &str = "[*]";
+
+impl<TState, TEvent, TPayload> StateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy
+{
+    /// Renders the state machine definition as a [Mermaid] `stateDiagram-v2`,
+    /// labelling states and events with their [`Display`] implementations
+    ///
+    /// _* the initial state, and the end of end states, are rendered as `[*]`,
+    /// and transitions are sorted by label, so the output is deterministic_
+    ///
+    /// # Results
+    ///
+    /// Returns the [Mermaid] diagram
+    ///
+    /// [Mermaid]: https://mermaid.js.org/syntax/stateDiagram.html
+    #[must_use]
+    pub fn to_mermaid(&self) -> String
+        where TState: Display,
+              TEvent: Display
+    {
+        self.to_mermaid_with(ToString::to_string, ToString::to_string)
+    }
+
+    /// Renders the state machine definition as a [Mermaid] `stateDiagram-v2`,
+    /// labelling states and events with custom formatting, _i.e. with their
+    /// [`Debug`] implementations_
+    ///
+    /// _* the initial state, and the end of end states, are rendered as `[*]`,
+    /// and transitions are sorted by label, so the output is deterministic_
+    ///
+    /// # Arguments
+    ///
+    /// * _`state_label`_ - formats the label of a state
+    /// * _`event_label`_ - formats the label of an event
+    ///
+    /// # Results
+    ///
+    /// Returns the [Mermaid] diagram
+    ///
+    /// [Mermaid]: https://mermaid.js.org/syntax/stateDiagram.html
+    /// [`Debug`]: std::fmt::Debug
+    pub fn to_mermaid_with(
+        &self,
+        state_label: impl Fn(&TState) -> String,
+        event_label: impl Fn(&TEvent) -> String,
+    ) -> String {
+        let label = |state: &TState| if *state == self.initial_state {
+            PSEUDO_STATE.to_string()
+        } else {
+            state_label(state)
+        };
+
+        // start transitions are listed first, then by label
+        let edges = transitions(self)
+            .map(|(from, event, next)| (from != self.initial_state, label(&from), event_label(&event), label(&next)))
+            .collect::<BTreeSet<_>>();
+
+        let ends = self.end_states.iter()
+            .map(&state_label)
+            .collect::<BTreeSet<_>>();
+
+        let edges = edges.into_iter()
+            .map(|(_, from, event, next)| format!("    {from} --> {next} : {event}\n"));
+
+        let ends = ends.into_iter()
+            .map(|end| format!("    {end} --> {PSEUDO_STATE}\n"));
+
+        once(String::from("stateDiagram-v2\n"))
+            .chain(edges)
+            .chain(ends)
+            .collect()
+    }
+}
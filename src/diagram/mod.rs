@@ -0,0 +1,36 @@
+//! Diagram renderers of state machine definitions
+
+use std::hash::Hash;
+
+use crate::StateMachineDefinition;
+
+mod dot;
+mod mermaid;
+
+/// Gets all of the labelled transition edges of a state machine definition,
+/// including guarded transitions and resolved transitions to the previous state
+///
+/// # Arguments
+///
+/// * _`definition`_ - the state machine definition
+///
+/// # Results
+///
+/// Returns an `Iterator<Item=(TState, TEvent, TState)>`, which are (from, event, to)
+/// transition edges
+pub fn transitions<TState, TEvent, TPayload>(
+    definition: &StateMachineDefinition<TState, TEvent, TPayload>,
+) -> impl Iterator<Item=(TState, TEvent, TState)> + '_
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy
+{
+    let transitions = definition.transitions.iter()
+        .flat_map(|(from, edges)| edges.iter().map(move |(event, next)| (*from, *event, *next)));
+
+    let guarded = definition.guards.iter()
+        .flat_map(|(from, edges)| edges.iter()
+            .flat_map(move |(event, candidates)| candidates.iter()
+                .map(move |(next, _)| (*from, *event, *next))));
+
+    transitions.chain(guarded).chain(definition.previous_transitions())
+}
@@ -87,6 +87,90 @@ impl<T> Graph<T>
         edges.insert(dest);
     }
 
+    /// Gets all of the edges of the directed graph that participate in a cycle
+    ///
+    /// _an edge, source > destination, participates in a cycle if it is a self loop,
+    /// or if both nodes are members of the same strongly connected component_
+    ///
+    /// # Results
+    ///
+    /// Returns a collection of (source, destination) edges
+    pub fn cyclical_edges(&self) -> HashSet<(T, T)> {
+        let components = self.strongly_connected_components();
+
+        self.adj.iter()
+            .flat_map(|(src, edges)| edges.iter().map(move |dest| (*src, *dest)))
+            .filter(|(src, dest)| src == dest || components[src] == components[dest])
+            .collect()
+    }
+
+    /// Labels each node of the directed graph with its strongly connected component,
+    /// in a single pass of [Tarjan's strongly connected components algorithm]
+    ///
+    /// # Results
+    ///
+    /// Returns the component of each node
+    ///
+    /// [Tarjan's strongly connected components algorithm]: https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm
+    fn strongly_connected_components(&self) -> HashMap<T, usize> {
+        let mut search = Components {
+            component: HashMap::with_capacity(self.v),
+            index: HashMap::with_capacity(self.v),
+            low_link: HashMap::with_capacity(self.v),
+            stack: Vec::with_capacity(self.v),
+        };
+
+        for node in self.adj.keys().copied() {
+            if !search.index.contains_key(&node) {
+                self.strong_connect(node, &mut search);
+            }
+        }
+
+        search.component
+    }
+
+    /// Recursively visits the nodes reachable from a node, labelling each completed
+    /// strongly connected component with the index of its root node
+    ///
+    /// # Arguments
+    ///
+    /// * _`node`_ - the node to visit
+    /// * _`search`_ - a mutable reference to the state of the search
+    fn strong_connect(&self, node: T, search: &mut Components<T>) {
+        let index = search.index.len();
+
+        search.index.insert(node, index);
+        search.low_link.insert(node, index);
+        search.stack.push(node);
+
+        for next in self.adj.get(&node).expect(Self::EXPECTED_NODE).iter().copied() {
+            let low_link = if !search.index.contains_key(&next) {
+                self.strong_connect(next, search);
+
+                search.low_link[&next]
+            } else if search.component.contains_key(&next) {
+                // the node belongs to a completed component
+                continue;
+            } else {
+                search.index[&next]
+            };
+
+            if low_link < search.low_link[&node] {
+                search.low_link.insert(node, low_link);
+            }
+        }
+
+        if search.low_link[&node] == index {
+            while let Some(member) = search.stack.pop() {
+                search.component.insert(member, index);
+
+                if member == node {
+                    break;
+                }
+            }
+        }
+    }
+
     // Checks if directed graph has any cycles
     ///
     /// # Results
@@ -135,7 +219,7 @@ impl<T> Graph<T>
     ///
     /// * _`visited`_ - a mutable reference to a history of visited nodes
     /// * _`stack`_ - a mutable reference to a collection of nodes that can
-    /// indicate a cycle in the current searched branch
+    ///   indicate a cycle in the current searched branch
     ///
     /// # Results
     ///
@@ -166,4 +250,12 @@ impl<T> Graph<T>
 
         false
     }
+}
+
+// State of a search for the strongly connected components of a directed graph
+struct Components<T> {
+    component: HashMap<T, usize>,
+    index: HashMap<T, usize>,
+    low_link: HashMap<T, usize>,
+    stack: Vec<T>,
 }
\ No newline at end of file
@@ -35,7 +35,7 @@
 //! Not all the validations of the state machine can be reasoned about at compile time.
 //!
 //! Validation also occurs during various state machine definition operations and then at
-//! the final [`build`] operation.
+//! the final `build` operation.
 //!
 //! It is possible to end up with the following invalid state machine definition issues;
 //!
@@ -47,14 +47,206 @@
 //!     * Unreachable states
 //!     * Undefined states
 //!
-//! _* see_ [`BuildError`] _for more details_
+//! _* see_ `BuildError` _for more details_
+//!
+//! ### Exhaustive Validation
+//!
+//! When the states and events implement `Enumerable`, _which can be derived with the `derive`
+//! feature_, `build_strict` also reports the states left out of the definition and the events
+//! no transition uses, _i.e. a forgotten_ `EMailState`. The `coverage` of a definition is its
+//! event × state matrix, which displays as a table.
 //!
 //! ## Detecting Cycles
 //!
-//! [`FiniteStateMachine<TState, TEvent>`] is defined as a directed graph,
-//! and it is possible to define [cyclical] states, the [`has_cycle`]
+//! `FiniteStateMachine<TState, TEvent>` is defined as a directed graph,
+//! and it is possible to define [cyclical] states, the `has_cycle`
 //! method checks if a cycle exists
 //!
+//! ## Guarded Transitions
+//!
+//! An event can transition to one of several states, each guarded by a predicate. Guards are
+//! evaluated in the order they are defined, and the first permitted transition is taken; an
+//! unguarded transition on the same event is used as a fallback.
+//!
+//! ## Global Transitions
+//!
+//! A transition shared by many states, _i.e. `Cancel` to `Canceled`_, can be defined once with
+//! `add_global_transition`, from every state that is not an end state, or with `add_group_transition`,
+//! from each state of a group of states. A transition on the same event defined by a state takes
+//! precedence over a group transition, which takes precedence over a global transition. Global and
+//! group transitions are validated by `build`, and reported by `next_states` like any other transition.
+//!
+//! ## Previous State Transitions
+//!
+//! A state entered from many states, _i.e. `Paused`_, can define a transition to the previous
+//! state with the `transition_to_previous_on` methods, which resolves to the state the state machine
+//! was in before it entered the current state; `next_states` shows the resolved state. Building
+//! the definition fails with a `NoPreviousState` error if such a state can be entered from the
+//! initial state.
+//!
+//! ## Timed Transitions
+//!
+//! A state can define a timed transition with the `transition_after` methods, _i.e. `Verifying` moves
+//! to `Failed` on a `Timeout` event if it does not `Succeed` within 30 minutes_. A running state
+//! machine reports the deadline of its current state with `next_deadline`, and `tick` fires an expired
+//! timeout as a normal transition, through the triggers of the transition. States are timed by a
+//! `Clock`, the `SystemClock` by default, or any clock set in the `StateMachineOptions` given to `create_with`, _i.e. a fake clock
+//! in tests_.
+//!
+//! ## Deferred Events
+//!
+//! A state can `defer` events it can not transition on; a deferred event is held by the state machine,
+//! instead of returning an `InvalidTransition` error, and is retried after each successful transition,
+//! until a state can transition on it. A held event is discarded once the state machine enters a state
+//! that neither transitions on, nor defers, the event. Held events are reported by `deferred_events`, they
+//! are not transition edges of `next_states`.
+//!
+//! ## Actions
+//!
+//! Besides triggers, a state can define `on_entry` and `on_exit` actions, and `on_transition`
+//! actions for a specific event. Actions are run after all triggers succeed, in the order exit,
+//! transition, then entry, so a vetoed transition does not run its actions. Actions are part of the
+//! definition, so clearing triggers does not clear actions.
+//!
+//! ## Undo
+//!
+//! A state machine can `undo` its last transition, stepping back to the state it was in before the
+//! transition and running the `on_undo` compensating actions of the undone state. The transitions of an
+//! active sub state machine are undone first, and undoing the transition into a composite state
+//! restores the sub state machine of the prior state. An undone transition is recorded in the journal
+//! as an `undone` entry, and deferred events are discarded. Undoing the start transition resets the state
+//! machine to its initial state, recording the `undone` entry in the reset journal, and undoing a state machine with no transitions returns a `NothingToUndo` error. Only the last `64`
+//! transitions can be undone, a different limit is set with the `undo_depth` of the `StateMachineOptions` given to `create_with`.
+//!
+//! ## Fallible Triggers
+//!
+//! A `FallibleTrigger` defined with the `try_trigger` methods, or with `new_fallible_triggers`,
+//! returns a `Result`; a failed trigger vetoes the transition, the state machine remains in its
+//! current state, and the event returns a `TriggerFailed` error with the source of the failure.
+//!
+//! ## Queued Triggers
+//!
+//! A trigger defined with the `queued_trigger` methods receives the `EventQueue` of the state machine,
+//! and can `post` follow-up events, _i.e. to cascade from `Sent` to an automatic `Verify`_. Follow-up
+//! events are processed in order, to completion, before the event that posted them returns. A state
+//! machine processes at most `64` follow-up events for an event, or the `max_depth` of the
+//! `StateMachineOptions` given to `create_with`; exceeding it, _i.e. an infinite loop of follow-up events_, returns a
+//! `QueueOverflow` error.
+//!
+//! ## Sub State Machines
+//!
+//! A state can embed a sub state machine with `sub_machine`, making it a composite state.
+//! Entering the composite state starts the sub state machine with its start event; events are
+//! handled by the active sub state machine first, and events it can not handle, or any event once
+//! it has ended, fall through to the transitions of the composite state. `current_path` returns
+//! the current state followed by the current states of the active sub state machines.
+//!
+//! ## Parallel Regions
+//!
+//! Independent aspects of a workflow, _i.e. delivery and billing_, can be defined as parallel
+//! regions; `add_region` completes the states of the current region and starts a new one, and
+//! `build_parallel` builds a `ParallelStateMachineDefinition`, validating each region the same as
+//! `build`. Every event is delivered to all regions, each region transitions on its own transition
+//! edges, and the `ParallelFiniteStateMachine` is only `is_end` once every region has ended.
+//!
+//! ## Thread Safety
+//!
+//! With the `sync` feature, triggers, actions and guards must be `Send + Sync`, and a
+//! `StateMachineDefinition` is backed by `Arc`, so a single definition can be shared across
+//! threads, and the state machines it creates are `Send`.
+//!
+//! ## Async Triggers
+//!
+//! With the `async` feature, triggers defined with the `async_trigger` methods return a future.
+//! A state machine created with `create_async` implements `AsyncFiniteStateMachine`, its `event`
+//! and `start` methods await the triggers of a transition in order. The futures are runtime
+//! agnostic, and with the `sync` feature they are `Send`. A state machine created with `create`
+//! refuses a transition with async triggers, returning an `AsyncTrigger` error.
+//!
+//! ## Transition Journal
+//!
+//! A state machine created with `create_with` and `journal` options records its transitions in a bounded
+//! journal, read with `history`, each entry has the event, the prior and next states, and a
+//! sequence number. Once the journal reaches its capacity the oldest entries are discarded;
+//! `reset` clears the journal, or archives it, read with `archived_history`, if configured to.
+//!
+//! ## Event Replay
+//!
+//! A state machine can be rebuilt from an event log with `replay`, which starts a new state machine
+//! with the first event and transitions on each subsequent event, or with `replay_without_triggers`
+//! to replay the events without invoking triggers. An event log that is not valid for the state
+//! machine definition returns a `ReplayFailed` error, with the index of the first event that fails.
+//!
+//! ## Snapshots
+//!
+//! With the `serde` feature, a running state machine can export a serializable `snapshot` of its
+//! current state, and a `StateMachineDefinition` can `restore` a state machine from a snapshot,
+//! _i.e. after a process restart_. Restoring a snapshot of an undefined state returns an
+//! `UndefinedStates` error. A snapshot saves the current and previous states, and the `history` of the
+//! journal, which `restore_with` loads into the journal of its `StateMachineOptions`; the undo history
+//! is not saved, so a restored state machine has nothing to `undo`.
+//!
+//! ## Declarative Definitions
+//!
+//! With the `serde` feature, a state machine can be defined with a `Schema`, deserialized from a
+//! data file, _i.e. JSON, TOML or YAML_. A schema is built with the same validations as the
+//! builder, the errors of a state are an `InvalidStateSchema` error with the index of the state in the
+//! schema, and triggers are bound afterwards by state, with `new_triggers`.
+//!
+//! ## Derive Macro
+//!
+//! With the `derive` feature, `#[derive(StateMachine)]` on a `State` enum generates a
+//! `state_machine` function that returns the `StateMachineDefinition` annotated on its variants,
+//! with `#[start(on = Event)]`, `#[transition(Event => State)]` and `#[end]`. The definition is
+//! validated at compile time; undefined and unreachable states are compile errors that point at
+//! the variant.
+//!
+//! ## Expression Macro
+//!
+//! With the `dsl` feature, the `state_machine!` macro defines a state machine with a [Mermaid] like
+//! state diagram, _i.e._ `[*] --Start--> A; A --Next--> B; B --> [*];`, that expands to the builder
+//! calls of the definition. Duplicate states and duplicate transitions are compile errors that name
+//! the offending line.
+//!
+//! ## Diagrams
+//!
+//! A `StateMachineDefinition` can be rendered as a [Mermaid] `stateDiagram-v2` with
+//! `to_mermaid`, labelling states and events with `Display`, or with `to_mermaid_with` for
+//! custom labels, _i.e._ `Debug`. The output is sorted, so generated diagrams do not drift.
+//!
+//! It can also be rendered as a [Graphviz] DOT digraph with `to_dot` or `to_dot_with`; start
+//! states are bold, end states are double circles, and edges that participate in a cycle are red.
+//!
+//! [Graphviz]: https://graphviz.org
+//! [Mermaid]: https://mermaid.js.org/syntax/stateDiagram.html
+//!
+//! ## Event Payloads
+//!
+//! A state machine built with `StateMachineBuilder::with_payload` delivers a payload with each
+//! event, using `event_with` and `start_with`, to triggers defined with the `_with` trigger
+//! methods, _i.e._ an error reason delivered with a `Fail` event. Events are still matched by the
+//! event alone.
+//!
+//! ## State Tables
+//!
+//! Without the default `std` feature, the crate is `no_std` and provides only [`StateTable`], state machine
+//! definitions of fixed arrays indexed by state and event, which are built in a `const` context or
+//! generated by the `state_table!` macro. Its [`TableStateMachine`] looks up transitions by index, invokes function
+//! pointer triggers and never allocates, so it runs on embedded targets. A table generated by `state_table!` is
+//! validated at compile time, a duplicate transition or end state, an unreachable state, or a table without
+//! start transitions or end states does not compile.
+//!
+//! [`StateTable`]: table::StateTable
+//! [`TableStateMachine`]: table::TableStateMachine
+//!
+//! ## Dense Definitions
+//!
+//! A definition built with `build_dense` assigns dense indices to its states, and indexes its events by their
+//! `Indexed` index, so its transitions are a flat table lookup that does not hash the event, its end
+//! states a bitset and its triggers are stored by state index. It supports triggers and follow-up events, but not actions, deferred events, guarded,
+//! previous state or timed transitions, or sub state machines. `cargo bench` compares it with the
+//! `StateMachineDefinition`.
+//!
 //! # Example
 //!
 //! This example demonstrates all of the possible definable state machine components;
@@ -74,14 +266,20 @@
 //! ```rust
 //! use std::fmt::{Display, Formatter};
 //!
+//! # #[cfg(feature = "std")]
 //! use stately::builder;
+//! # #[cfg(feature = "std")]
 //! use stately::prelude::*;
 //!
 //! use crate::Event::*;
 //! use crate::State::*;
 //!
+//! # #[cfg(feature = "std")]
 //! type BuilderResult = builder::Result<(), State, Event>;
 //!
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! # #[cfg(feature = "std")]
 //! fn main() -> BuilderResult {
 //!     // start with a new builder and an initial value
 //!     let example = StateMachineBuilder::new()
@@ -149,11 +347,8 @@
 //! ```
 //!
 //! [finite state machine]: https://en.wikipedia.org/wiki/Finite-state_machine
-//! [`build`]: builder::BuilderState::build
-//! [`BuildError`]: builder::BuilderError
-//! [`FiniteStateMachine<TState, TEvent>`]: FiniteStateMachine
 //! [cyclical]: https://en.wikipedia.org/wiki/Cycle_(graph_theory)
-//! [`has_cycle`]: FiniteStateMachine::has_cycles
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
 #![deny(clippy::nursery)]
@@ -166,18 +361,104 @@
 #![deny(rustdoc::invalid_codeblock_attributes)]
 #![deny(rustdoc::invalid_html_tags)]
 #![deny(rustdoc::invalid_rust_codeblocks)]
-#![deny(rustdoc::broken_intra_doc_links)]
 
 // dispensation from the pope
 #![allow(clippy::module_name_repetitions)] // this one makes naming modules difficult
 #![allow(clippy::items_after_statements)]  // I'm ok with code organization
 
+#[cfg(feature = "std")]
 #[doc(inline)]
 pub use builder::StateMachineBuilder;
+#[cfg(feature = "derive")]
 #[doc(inline)]
-pub use state_machine::{detect_cycles, FiniteStateMachine, StateMachineDefinition};
+pub use stately_macros::{Enumerable, StateMachine};
+#[cfg(feature = "dsl")]
+#[doc(inline)]
+pub use stately_macros::state_machine;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use state_machine::{
+    DenseStateMachineDefinition, detect_cycles, Enumerable, FiniteStateMachine, ParallelFiniteStateMachine,
+    ParallelStateMachineDefinition, StateMachineDefinition,
+};
+
+/// A marker for the bounds of callbacks, _i.e. triggers, actions and guards_
+///
+/// _* with the `sync` feature callbacks must be `Send + Sync`, so that state
+/// machine definitions can be shared across threads_
+#[cfg(feature = "sync")]
+pub trait Shareable: Send + Sync {}
+
+#[cfg(feature = "sync")]
+impl<T: Send + Sync> Shareable for T {}
+
+/// A marker for the bounds of callbacks, _i.e. triggers, actions and guards_
+///
+/// _* with the `sync` feature callbacks must be `Send + Sync`, so that state
+/// machine definitions can be shared across threads_
+#[cfg(not(feature = "sync"))]
+pub trait Shareable {}
+
+#[cfg(not(feature = "sync"))]
+impl<T> Shareable for T {}
+
+/// A marker for the bounds of futures, _i.e. the futures of async triggers_
+///
+/// _* with the `sync` feature futures must be `Send`, so that the futures of an
+/// [`AsyncFiniteStateMachine`] can be spawned on multi-threaded runtimes_
+///
+/// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+#[cfg(all(feature = "async", feature = "sync"))]
+pub trait Sendable: Send {}
+
+#[cfg(all(feature = "async", feature = "sync"))]
+impl<T: Send> Sendable for T {}
+
+/// A marker for the bounds of futures, _i.e. the futures of async triggers_
+///
+/// _* with the `sync` feature futures must be `Send`, so that the futures of an
+/// [`AsyncFiniteStateMachine`] can be spawned on multi-threaded runtimes_
+///
+/// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+#[cfg(all(feature = "async", not(feature = "sync")))]
+pub trait Sendable {}
+
+#[cfg(all(feature = "async", not(feature = "sync")))]
+impl<T> Sendable for T {}
+
+/// A type that represents the future of an [`AsyncTrigger`]
+#[cfg(all(feature = "async", not(feature = "sync")))]
+pub type TriggerFuture = std::pin::Pin<Box<dyn std::future::Future<Output=()>>>;
+
+/// A type that represents the future of an [`AsyncTrigger`]
+#[cfg(all(feature = "async", feature = "sync"))]
+pub type TriggerFuture = std::pin::Pin<Box<dyn std::future::Future<Output=()> + Send>>;
+
+/// A type that represents an async event transition trigger callback, which
+/// is awaited by an [`AsyncFiniteStateMachine`]
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the next state
+///
+/// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+#[cfg(all(feature = "async", not(feature = "sync")))]
+pub type AsyncTrigger<TState, TEvent> = Box<dyn Fn(TEvent, TState, TState) -> TriggerFuture>;
 
-// type TransitionPredicate<TState> = Box<dyn Fn(TState) -> bool>;
+/// A type that represents an async event transition trigger callback, which
+/// is awaited by an [`AsyncFiniteStateMachine`]
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the next state
+///
+/// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+#[cfg(all(feature = "async", feature = "sync"))]
+pub type AsyncTrigger<TState, TEvent> = Box<dyn Fn(TEvent, TState, TState) -> TriggerFuture + Send + Sync>;
 
 /// A type that represents an event transition trigger callback
 ///
@@ -186,12 +467,135 @@ pub use state_machine::{detect_cycles, FiniteStateMachine, StateMachineDefinitio
 /// * _`TEvent`_ - the event that triggered the transition
 /// * _`TState`_ -  the prior state
 /// * _`TState`_ -  the current state
+#[cfg(all(feature = "std", not(feature = "sync")))]
 pub type Trigger<TState, TEvent> = Box<dyn Fn(TEvent, TState, TState)>;
 
+/// A type that represents an event transition trigger callback
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the current state
+#[cfg(feature = "sync")]
+pub type Trigger<TState, TEvent> = Box<dyn Fn(TEvent, TState, TState) + Send + Sync>;
+
+/// A type that represents the error of a failed `FallibleTrigger`
+#[cfg(all(feature = "std", not(feature = "sync")))]
+pub type TriggerError = Box<dyn std::error::Error>;
+
+/// A type that represents the error of a failed `FallibleTrigger`
+#[cfg(feature = "sync")]
+pub type TriggerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A type that represents a fallible event transition trigger callback,
+/// a failed trigger vetoes the transition
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the next state
+#[cfg(all(feature = "std", not(feature = "sync")))]
+pub type FallibleTrigger<TState, TEvent> =
+    Box<dyn Fn(TEvent, TState, TState) -> Result<(), TriggerError>>;
+
+/// A type that represents a fallible event transition trigger callback,
+/// a failed trigger vetoes the transition
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the next state
+#[cfg(feature = "sync")]
+pub type FallibleTrigger<TState, TEvent> =
+    Box<dyn Fn(TEvent, TState, TState) -> Result<(), TriggerError> + Send + Sync>;
+
+/// A type that represents an event transition trigger callback, which
+/// receives the payload of the event
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the current state
+/// * _`&TPayload`_ -  the payload delivered with the event
+#[cfg(all(feature = "std", not(feature = "sync")))]
+pub type PayloadTrigger<TState, TEvent, TPayload> = Box<dyn Fn(TEvent, TState, TState, &TPayload)>;
+
+/// A type that represents an event transition trigger callback, which
+/// receives the payload of the event
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the current state
+/// * _`&TPayload`_ -  the payload delivered with the event
+#[cfg(feature = "sync")]
+pub type PayloadTrigger<TState, TEvent, TPayload> =
+    Box<dyn Fn(TEvent, TState, TState, &TPayload) + Send + Sync>;
+
+/// A type that represents an event transition trigger callback, which can
+/// post follow-up events to the [`EventQueue`] of the state machine
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the current state
+/// * _`&mut EventQueue`_ -  the queue of follow-up events
+///
+/// [`EventQueue`]: crate::state_machine::EventQueue
+#[cfg(all(feature = "std", not(feature = "sync")))]
+pub type QueuedTrigger<TState, TEvent, TPayload> =
+    Box<dyn Fn(TEvent, TState, TState, &mut state_machine::EventQueue<TEvent, TPayload>)>;
+
+/// A type that represents an event transition trigger callback, which can
+/// post follow-up events to the [`EventQueue`] of the state machine
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the current state
+/// * _`&mut EventQueue`_ -  the queue of follow-up events
+///
+/// [`EventQueue`]: crate::state_machine::EventQueue
+#[cfg(feature = "sync")]
+pub type QueuedTrigger<TState, TEvent, TPayload> =
+    Box<dyn Fn(TEvent, TState, TState, &mut state_machine::EventQueue<TEvent, TPayload>) + Send + Sync>;
+
+/// A type that represents the guard predicate of a guarded transition
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event triggering the transition
+/// * _`TState`_ -  the current state
+#[cfg(all(feature = "std", not(feature = "sync")))]
+pub type TransitionPredicate<TState, TEvent> = Box<dyn Fn(TEvent, TState) -> bool>;
+
+/// A type that represents the guard predicate of a guarded transition
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event triggering the transition
+/// * _`TState`_ -  the current state
+#[cfg(feature = "sync")]
+pub type TransitionPredicate<TState, TEvent> = Box<dyn Fn(TEvent, TState) -> bool + Send + Sync>;
+
+#[cfg(feature = "std")]
 pub mod builder;
+#[cfg(feature = "std")]
+mod diagram;
+#[cfg(feature = "std")]
 mod graph;
+#[cfg(feature = "std")]
 pub mod state_machine;
+pub mod table;
 
+#[cfg(feature = "std")]
 pub mod prelude {
     //! Required public api for using `Stately`; grouped for convenience
     #[doc(no_inline)]
@@ -201,8 +605,18 @@ pub mod prelude {
         TriggerState,
     };
     #[doc(no_inline)]
-    pub use super::state_machine::{FiniteStateMachine, StateMachineDefinition};
+    pub use super::state_machine::{
+        DenseStateMachineDefinition, FiniteStateMachine, ParallelFiniteStateMachine,
+        ParallelStateMachineDefinition, StateMachineDefinition,
+    };
+    #[cfg(feature = "async")]
+    #[doc(no_inline)]
+    pub use super::state_machine::AsyncFiniteStateMachine;
 }
 
-#[cfg(test)]
+// lets the macro generated state machines of the tests refer to `::stately`
+#[cfg(all(test, any(feature = "derive", feature = "dsl")))]
+extern crate self as stately;
+
+#[cfg(all(test, feature = "std"))]
 mod tests;
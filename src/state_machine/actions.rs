@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Trigger;
+
+/// Entry, exit, transition and compensating actions of a state machine definition
+///
+/// Actions are invoked once all the triggers of a transition succeed, in the order;
+/// _exit_ actions of the prior state, _transition_ actions of the transition edge,
+/// and then _entry_ actions of the next state
+///
+/// _compensating actions are only invoked when a transition is undone_
+pub struct Actions<TState, TEvent> {
+    pub(crate) compensate: HashMap<TState, Vec<Trigger<TState, TEvent>>>,
+    pub(crate) entry: HashMap<TState, Vec<Trigger<TState, TEvent>>>,
+    pub(crate) exit: HashMap<TState, Vec<Trigger<TState, TEvent>>>,
+    pub(crate) transition: HashMap<(TState, TEvent), Vec<Trigger<TState, TEvent>>>,
+}
+
+impl<TState, TEvent> Default for Actions<TState, TEvent> {
+    fn default() -> Self {
+        Self {
+            compensate: HashMap::default(),
+            entry: HashMap::default(),
+            exit: HashMap::default(),
+            transition: HashMap::default(),
+        }
+    }
+}
+
+impl<TState, TEvent> Actions<TState, TEvent>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash
+{
+    /// Invokes the compensating actions of an undone transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the event of the undone transition
+    /// * _`undone`_ - the state being undone
+    /// * _`restored`_ - the state being restored
+    pub fn compensate(&self, event: TEvent, undone: TState, restored: TState) {
+        for action in self.compensate.get(&undone).into_iter().flatten() {
+            action(event, undone, restored);
+        }
+    }
+
+    /// Invokes the actions of a transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the event that triggered the transition
+    /// * _`prior`_ - the prior state
+    /// * _`next`_ - the next state
+    pub fn invoke(&self, event: TEvent, prior: TState, next: TState) {
+        let exit = self.exit.get(&prior);
+        let transition = self.transition.get(&(prior, event));
+        let entry = self.entry.get(&next);
+
+        for action in [exit, transition, entry].into_iter().flatten().flatten() {
+            action(event, prior, next);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use std::future::Future;
+use std::ops::Deref;
+
+use crate::{state_machine, Sendable};
+
+/// _`AsyncFiniteStateMachine<TState, TEvent>`_ is a trait representing the
+/// api of a [state machine] which awaits its triggers
+///
+/// The futures of the state machine are runtime agnostic, they can be awaited
+/// by any executor
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
+///
+/// ### `Deref<Target=TState>`
+///
+/// `AsyncFiniteStateMachine` implements `Deref` with a target of type of `TState`,
+/// de-referencing to the current state
+///
+/// [state machine]: https://en.wikipedia.org/wiki/Finite-state_machine
+pub trait AsyncFiniteStateMachine<TState, TEvent, TPayload = ()>: Deref<Target=TState> {
+    /// Gets the current state of the state machine
+    ///
+    fn current_state(&self) -> TState;
+
+    /// Transitions the state machine from the current state to the next
+    /// state based on the defined event transition edge, awaiting the
+    /// triggers of the transition in order
+    ///
+    /// _* triggers receive the default payload_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event triggering the transition
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine after the transition event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors transitioning on event
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn event(
+        &mut self,
+        event: TEvent,
+    ) -> impl Future<Output=state_machine::Result<TState, TState, TEvent>> + Sendable
+        where TPayload: Default
+    {
+        self.event_with(event, TPayload::default())
+    }
+
+    /// Transitions the state machine from the current state to the next
+    /// state based on the defined event transition edge, delivering a
+    /// payload to the triggers of the transition, awaiting the triggers
+    /// in order
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event triggering the transition
+    /// * _`payload`_ - payload delivered to the triggers
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine after the transition event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors transitioning on event
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn event_with(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+    ) -> impl Future<Output=state_machine::Result<TState, TState, TEvent>> + Sendable;
+
+    /// Checks if the current state of the state machine is an end state
+    ///
+    /// # Results
+    ///
+    /// Returns `true` if the state machine has ended, `false` otherwise
+    fn is_end(&self) -> bool;
+
+    /// Checks if the state machine has started
+    ///
+    /// # Results
+    ///
+    /// Returns `true` if the state machine has started, `false` otherwise
+    fn is_started(&self) -> bool;
+
+    /// Resets the state machine to it's initial state
+    ///
+    /// # Results
+    ///
+    /// Returns the prior state of the state machine before it was reset
+    fn reset(&mut self) -> TState;
+
+    /// Starts the state machine, awaiting the triggers of the start state
+    /// in order
+    ///
+    /// _* triggers receive the default payload_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event starting the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors starting the state machine
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn start(
+        &mut self,
+        event: TEvent,
+    ) -> impl Future<Output=state_machine::Result<TState, TState, TEvent>> + Sendable
+        where TPayload: Default
+    {
+        self.start_with(event, TPayload::default())
+    }
+
+    /// Starts the state machine, delivering a payload to the triggers
+    /// of the start state, awaiting the triggers in order
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event starting the state machine
+    /// * _`payload`_ - payload delivered to the triggers
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors starting the state machine
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn start_with(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+    ) -> impl Future<Output=state_machine::Result<TState, TState, TEvent>> + Sendable;
+}
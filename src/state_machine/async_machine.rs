@@ -0,0 +1,228 @@
+use std::hash::Hash;
+use std::ops::Deref;
+
+use crate::state_machine::{AsyncFiniteStateMachine, EventQueue, Result, Shared, StateMachineDefinition};
+use crate::state_machine::fsm::FiniteStateMachine;
+use crate::state_machine::machine::StateMachine;
+use crate::state_machine::StateError::{
+    EndState, GuardRejected, InvalidTransition, NotStarted, QueueOverflow, TriggerFailed
+};
+use crate::Shareable;
+
+// StateMachineBuilder built implementation of an AsyncFiniteStateMachine trait object
+pub struct AsyncStateMachine<TState, TEvent, TPayload> {
+    machine: StateMachine<TState, TEvent, TPayload>,
+}
+
+impl<TState, TEvent, TPayload> AsyncStateMachine<TState, TEvent, TPayload>
+    where TState: Copy
+{
+    /// Initializes a new instance of an async state machine
+    ///
+    /// # Arguments
+    ///
+    /// * _`definition`_ - an instance of state machine definition
+    ///
+    /// # Results
+    ///
+    /// Returns a new initialized instance of an async state machine
+    pub fn new(definition: StateMachineDefinition<TState, TEvent, TPayload>) -> Self {
+        Self {
+            machine: StateMachine::new(definition),
+        }
+    }
+}
+
+impl<TState, TEvent, TPayload> Deref for AsyncStateMachine<TState, TEvent, TPayload> {
+    type Target = TState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.machine.current_state
+    }
+}
+
+impl<TState, TEvent, TPayload> AsyncFiniteStateMachine<TState, TEvent, TPayload>
+    for AsyncStateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash + Shareable,
+          TEvent: Copy + Eq + Hash + Shareable,
+          TPayload: Shareable
+{
+    fn current_state(&self) -> TState {
+        self.machine.current_state
+    }
+
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn event_with(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        if !self.machine.is_started() {
+            return Err(NotStarted);
+        }
+
+        self.process(event, payload).await
+    }
+
+    fn is_end(&self) -> bool {
+        self.machine.is_end()
+    }
+
+    fn is_started(&self) -> bool {
+        self.machine.is_started()
+    }
+
+    fn reset(&mut self) -> TState {
+        self.machine.reset()
+    }
+
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn start_with(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        self.machine.check_start(event)?;
+
+        self.process(event, payload).await
+    }
+}
+
+impl<TState, TEvent, TPayload> AsyncStateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash + Shareable,
+          TEvent: Copy + Eq + Hash + Shareable,
+          TPayload: Shareable
+{
+    /// Transitions on an event, holding the event if it is deferred by the current
+    /// state, and retrying the held events after a successful transition
+    ///
+    /// # Results
+    ///
+    /// Returns the current state of the state machine
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn dispatch(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        match self.transition_on(event, &payload, queue).await {
+            Ok(_) => self.retry_deferred(queue).await?,
+            Err(GuardRejected { .. } | InvalidTransition { .. }) if self.machine.defers(event) =>
+                self.machine.deferred.push((event, payload)),
+            Err(error) => return Err(error),
+        }
+
+        Ok(self.machine.current_state)
+    }
+
+    /// Transitions on an event, then on the follow-up events posted by queued
+    /// triggers, in order, until the event queue is empty
+    ///
+    /// _a failed follow-up event discards the remaining follow-up events_
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn process(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        let mut queue = EventQueue::new();
+        let mut transition = self.dispatch(event, payload, &mut queue).await?;
+
+        for depth in 0.. {
+            let Some((event, payload)) = queue.next() else { break };
+
+            if depth == self.machine.max_depth {
+                return Err(QueueOverflow { event, max_depth: self.machine.max_depth });
+            }
+
+            transition = self.dispatch(event, payload, &mut queue).await?;
+        }
+
+        Ok(transition)
+    }
+
+    /// Retries the held deferred events, in the order they were deferred, until
+    /// none of them can transition; held events that are no longer deferred by
+    /// the current state are discarded
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if a retried event fails, other than an event
+    /// the current state can not transition on
+    ///
+    /// [`StateError`]: crate::state_machine::StateError
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn retry_deferred(&mut self, queue: &mut EventQueue<TEvent, TPayload>) -> Result<(), TState, TEvent> {
+        let mut index = 0;
+
+        while index < self.machine.deferred.len() {
+            let (event, payload) = self.machine.deferred.remove(index);
+
+            match self.transition_on(event, &payload, queue).await {
+                Ok(_) => index = 0,
+                Err(GuardRejected { .. } | InvalidTransition { .. }) if self.machine.defers(event) => {
+                    self.machine.deferred.insert(index, (event, payload));
+
+                    index += 1;
+                }
+                Err(EndState { .. } | GuardRejected { .. } | InvalidTransition { .. }) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transitions to the resolved next state of an event, awaiting triggers in order
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn transition_on(
+        &mut self,
+        event: TEvent,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        self.machine.transition_on_async(event, payload, queue).await
+    }
+}
+
+impl<TState, TEvent, TPayload> StateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash + Shareable,
+          TEvent: Copy + Eq + Hash + Shareable,
+          TPayload: Shareable
+{
+    /// Transitions to the resolved next state of an event, awaiting triggers in order,
+    /// the async counterpart of the transition of a [`StateMachine`]
+    ///
+    /// _the current state is only committed when all triggers succeed, a failed
+    /// trigger vetoes the transition and the remaining triggers, and the actions of
+    /// the transition, are not invoked_
+    ///
+    /// _an active sub state machine handles the event first, events it can not
+    /// handle fall through to the transitions of this state machine_
+    #[cfg_attr(not(feature = "sync"), allow(clippy::future_not_send))]
+    async fn transition_on_async(
+        &mut self,
+        event: TEvent,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        if let Some(child) = &mut self.child {
+            if Self::handled(Box::pin(child.transition_on_async(event, payload, queue)).await)? {
+                return Ok(self.current_state);
+            }
+        }
+
+        let current_state = self.current_state;
+        let transition = self.begin_transition(event)?;
+        let triggers = Shared::clone(&self.definition.triggers);
+
+        if let Some(triggers) = triggers.get(&transition).filter(|_| self.triggers) {
+            for trigger in triggers {
+                trigger.invoke_async(event, current_state, transition, payload, queue).await
+                    .map_err(|source| TriggerFailed { state: transition, event, source })?;
+            }
+        }
+
+        let child = match self.sub_machine(transition) {
+            Some((start, mut child)) => {
+                Box::pin(child.transition_on_async(start, payload, queue)).await?;
+
+                Some(child)
+            }
+            None => None,
+        };
+
+        self.complete_transition(event, transition, child);
+
+        Ok(transition)
+    }
+}
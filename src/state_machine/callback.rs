@@ -0,0 +1,98 @@
+#[cfg(feature = "async")]
+use crate::AsyncTrigger;
+use crate::{FallibleTrigger, PayloadTrigger, QueuedTrigger, Trigger, TriggerError};
+use crate::state_machine::EventQueue;
+
+/// The kinds of trigger callbacks that can be invoked on transition
+pub enum Callback<TState, TEvent, TPayload> {
+    /// An [`AsyncTrigger`], which can only be awaited by an async state machine
+    #[cfg(feature = "async")]
+    Async(AsyncTrigger<TState, TEvent>),
+
+    /// A [`FallibleTrigger`], which can veto the transition
+    Fallible(FallibleTrigger<TState, TEvent>),
+
+    /// A [`PayloadTrigger`], which receives the payload of the event
+    Payload(PayloadTrigger<TState, TEvent, TPayload>),
+
+    /// A [`QueuedTrigger`], which can post follow-up events
+    Queued(QueuedTrigger<TState, TEvent, TPayload>),
+
+    /// A [`Trigger`], which ignores the payload of the event
+    Trigger(Trigger<TState, TEvent>),
+}
+
+impl<TState, TEvent, TPayload> Callback<TState, TEvent, TPayload> {
+    /// Invokes the trigger callback
+    ///
+    /// _* async triggers must be invoked with_ `invoke_async`
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the event that triggered the transition
+    /// * _`prior`_ - the prior state
+    /// * _`next`_ - the next state
+    /// * _`payload`_ - the payload delivered with the event
+    /// * _`queue`_ - the queue of follow-up events
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`TriggerError`] of a failed [`FallibleTrigger`]
+    pub fn invoke(
+        &self,
+        event: TEvent,
+        prior: TState,
+        next: TState,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<(), TriggerError> {
+        match self {
+            #[cfg(feature = "async")]
+            Self::Async(_) => unreachable!("async triggers can only be awaited"),
+            Self::Fallible(trigger) => return trigger(event, prior, next),
+            Self::Payload(trigger) => trigger(event, prior, next, payload),
+            Self::Queued(trigger) => trigger(event, prior, next, queue),
+            Self::Trigger(trigger) => trigger(event, prior, next),
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the trigger callback, awaiting async triggers
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the event that triggered the transition
+    /// * _`prior`_ - the prior state
+    /// * _`next`_ - the next state
+    /// * _`payload`_ - the payload delivered with the event
+    /// * _`queue`_ - the queue of follow-up events
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`TriggerError`] of a failed [`FallibleTrigger`]
+    #[cfg(feature = "async")]
+    #[allow(clippy::future_not_send)] // `Send` when the states, events and payload are `Sync`
+    pub async fn invoke_async(
+        &self,
+        event: TEvent,
+        prior: TState,
+        next: TState,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<(), TriggerError> {
+        if let Self::Async(trigger) = self {
+            trigger(event, prior, next).await;
+
+            Ok(())
+        } else {
+            self.invoke(event, prior, next, payload, queue)
+        }
+    }
+
+    /// Indicates if the trigger callback is an async trigger
+    #[cfg(feature = "async")]
+    pub const fn is_async(&self) -> bool {
+        matches!(self, Self::Async(_))
+    }
+}
@@ -0,0 +1,26 @@
+use std::time::Instant;
+
+use crate::Shareable;
+
+/// A source of the current time of a state machine, which times the states
+/// with timed transitions
+///
+/// _* a fake clock can be used to test timed transitions, without real timers_
+pub trait Clock: Shareable {
+    /// Gets the current time
+    ///
+    /// # Results
+    ///
+    /// Returns the current time of the clock
+    fn now(&self) -> Instant;
+}
+
+/// The [`Clock`] of the system, _the default clock of a state machine_
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+use crate::diagram::transitions;
+use crate::StateMachineDefinition;
+
+/// A state, or an event, type that lists every one of its values, _i.e. every
+/// variant of a fieldless enum_
+///
+/// _* with the `derive` feature, `Enumerable` can be derived for fieldless enums_
+///
+/// ```rust
+/// use stately::Enumerable;
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// enum Event {
+///     On, Push, Coin,
+/// }
+///
+/// impl Enumerable for Event {
+///     const VALUES: &'static [Self] = &[Self::On, Self::Push, Self::Coin];
+/// }
+/// ```
+pub trait Enumerable: Sized + 'static {
+    /// Every value of the type, _in declaration order_
+    const VALUES: &'static [Self];
+}
+
+/// The event × state coverage matrix of a state machine definition, including the
+/// states and events of its sub state machines
+///
+/// _* rows are states and columns are events, both in the order of their
+/// [`Enumerable::VALUES`]_
+///
+/// The coverage [`Display`]s as a table, each cell lists the next states of a
+/// transition, `-` marks an event the state does not transition on
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Coverage<TState, TEvent> {
+    pub(crate) matrix: Vec<Vec<Vec<TState>>>,
+    pub(crate) missing_states: Vec<TState>,
+    pub(crate) unused_events: Vec<TEvent>,
+}
+
+impl<TState, TEvent> Coverage<TState, TEvent>
+    where TState: Copy + Eq + Enumerable,
+          TEvent: Copy + Eq + Enumerable
+{
+    /// Indicates if every state is part of the state machine definition, and every
+    /// event is used by a transition
+    #[must_use]
+    pub const fn is_exhaustive(&self) -> bool {
+        self.missing_states.is_empty() && self.unused_events.is_empty()
+    }
+
+    /// Gets the states that are not part of the state machine definition, _i.e.
+    /// forgotten states_
+    #[must_use]
+    pub fn missing_states(&self) -> &[TState] {
+        &self.missing_states
+    }
+
+    /// Gets the next states of a state on an event
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the state the transition is from
+    /// * _`event`_ - the event of the transition
+    ///
+    /// # Results
+    ///
+    /// Returns the next states of the transition, _guarded transitions, and
+    /// transitions to the previous state, can have more than one next state_,
+    /// or an empty slice if the state does not transition on the event
+    #[must_use]
+    pub fn next_states(&self, state: TState, event: TEvent) -> &[TState] {
+        let row = TState::VALUES.iter().position(|value| *value == state);
+        let column = TEvent::VALUES.iter().position(|value| *value == event);
+
+        row.zip(column).map_or(&[], |(row, column)| &self.matrix[row][column])
+    }
+
+    /// Gets the events that are not used by any transition
+    #[must_use]
+    pub fn unused_events(&self) -> &[TEvent] {
+        &self.unused_events
+    }
+}
+
+impl<TState, TEvent> Display for Coverage<TState, TEvent>
+    where TState: Display + Enumerable,
+          TEvent: Display + Enumerable
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let header = std::iter::once(String::new())
+            .chain(TEvent::VALUES.iter().map(ToString::to_string));
+
+        let rows = TState::VALUES.iter().zip(&self.matrix)
+            .map(|(state, row)| std::iter::once(state.to_string())
+                .chain(row.iter().map(|next| if next.is_empty() {
+                    String::from("-")
+                } else {
+                    next.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+                }))
+                .collect::<Vec<_>>());
+
+        let table = std::iter::once(header.collect::<Vec<_>>()).chain(rows).collect::<Vec<_>>();
+
+        let widths = (0..=TEvent::VALUES.len())
+            .map(|column| table.iter().map(|row| row[column].chars().count()).max().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        for row in &table {
+            let cells = row.iter().zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>();
+
+            fmt.write_fmt(format_args!("{}\n", cells.join(" | ").trim_end()))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<TState, TEvent, TPayload> StateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash + Enumerable,
+          TEvent: Copy + Eq + Hash + Enumerable
+{
+    /// Gets the event × state [`Coverage`] matrix of the state machine definition,
+    /// _i.e. to find forgotten states and unused events_
+    ///
+    /// _* the states and events of sub state machines are included_
+    ///
+    /// # Results
+    ///
+    /// Returns the [`Coverage`] of every [`Enumerable`] state and event
+    #[must_use]
+    pub fn coverage(&self) -> Coverage<TState, TEvent> {
+        let mut definitions = vec![self];
+        let mut index = 0;
+
+        while index < definitions.len() {
+            definitions.extend(definitions[index].sub_machines.values().map(|(_, definition)| definition));
+
+            index += 1;
+        }
+
+        let edges = definitions.iter()
+            .flat_map(|definition| transitions(*definition))
+            .collect::<Vec<_>>();
+
+        let matrix = TState::VALUES.iter()
+            .map(|state| TEvent::VALUES.iter()
+                .map(|event| {
+                    let mut next_states = Vec::new();
+
+                    for (_, _, next) in edges.iter().filter(|(from, on, _)| from == state && on == event) {
+                        if !next_states.contains(next) {
+                            next_states.push(*next);
+                        }
+                    }
+
+                    next_states
+                })
+                .collect())
+            .collect();
+
+        let missing_states = TState::VALUES.iter()
+            .filter(|state| !definitions.iter().any(|definition|
+                definition.transitions.contains_key(state) || definition.end_states.contains(state)))
+            .copied()
+            .collect();
+
+        let unused_events = TEvent::VALUES.iter()
+            .filter(|event| !edges.iter().any(|(_, on, _)| on == *event))
+            .copied()
+            .collect();
+
+        Coverage { matrix, missing_states, unused_events }
+    }
+}
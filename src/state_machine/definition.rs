@@ -1,13 +1,35 @@
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
-use std::rc::Rc;
+use std::time::Duration;
 
 use crate::state_machine::fsm::FiniteStateMachine;
 use crate::state_machine::machine::StateMachine;
-use crate::Trigger;
+use crate::state_machine::{Actions, Callback, Result, Shared, StateMachineOptions};
+#[cfg(feature = "async")]
+use crate::state_machine::async_machine::AsyncStateMachine;
+#[cfg(feature = "async")]
+use crate::state_machine::AsyncFiniteStateMachine;
+#[cfg(feature = "async")]
+use crate::Shareable;
+#[cfg(feature = "serde")]
+use crate::state_machine::Snapshot;
+#[cfg(feature = "serde")]
+use crate::state_machine::StateError::UndefinedStates;
+use crate::TransitionPredicate;
+
+/// Guarded transitions of each state, candidate next states paired with their guards
+pub type Guards<TState, TEvent> =
+    HashMap<TState, HashMap<TEvent, Vec<(TState, TransitionPredicate<TState, TEvent>)>>>;
+
+/// Sub state machines of composite states, paired with the event that starts them
+pub type SubMachines<TState, TEvent, TPayload> =
+    HashMap<TState, (TEvent, StateMachineDefinition<TState, TEvent, TPayload>)>;
+
+/// Triggers of each state, invoked when transitioning to the state
+pub type Triggers<TState, TEvent, TPayload> = HashMap<TState, Vec<Callback<TState, TEvent, TPayload>>>;
 
 /// Definition of a state machine, including; _all states_, _transition
-/// edges_, and _optional triggers_
+/// edges_, _optional triggers_, and _optional sub state machines_
 ///
 /// An instance can be initialized with the [`StateMachineBuilder`] and
 /// is instantiated with the [`BuilderState::build`] method..
@@ -16,6 +38,8 @@ use crate::Trigger;
 ///
 /// * _`TState`_ - represents the states of a state machine
 /// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
 ///
 /// _i.e._
 ///
@@ -34,27 +58,319 @@ use crate::Trigger;
 ///
 /// [`StateMachineBuilder`]: crate::StateMachineBuilder
 /// [`BuilderState::build`]: crate::builder::BuilderState::build
-#[derive(Clone)]
-pub struct StateMachineDefinition<TState, TEvent> {
-    pub(crate) end_states: Rc<HashSet<TState>>,
+pub struct StateMachineDefinition<TState, TEvent, TPayload = ()> {
+    pub(crate) actions: Shared<Actions<TState, TEvent>>,
+    pub(crate) deferred: Shared<HashMap<TState, HashSet<TEvent>>>,
+    pub(crate) end_states: Shared<HashSet<TState>>,
+    pub(crate) guards: Shared<Guards<TState, TEvent>>,
     pub(crate) initial_state: TState,
-    pub(crate) transitions: Rc<HashMap<TState, HashMap<TEvent, TState>>>,
-    pub(crate) triggers: Rc<HashMap<TState, Vec<Trigger<TState, TEvent>>>>,
+    pub(crate) previous: Shared<HashMap<TState, HashSet<TEvent>>>,
+    pub(crate) sub_machines: Shared<SubMachines<TState, TEvent, TPayload>>,
+    pub(crate) timeouts: Shared<HashMap<TState, (Duration, TEvent)>>,
+    pub(crate) transitions: Shared<HashMap<TState, HashMap<TEvent, TState>>>,
+    pub(crate) triggers: Shared<Triggers<TState, TEvent, TPayload>>,
+}
+
+// derived `Clone` would needlessly require `TEvent` and `TPayload` to be `Clone`
+impl<TState, TEvent, TPayload> Clone for StateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy
+{
+    fn clone(&self) -> Self {
+        Self {
+            actions: self.actions.clone(),
+            deferred: self.deferred.clone(),
+            end_states: self.end_states.clone(),
+            guards: self.guards.clone(),
+            initial_state: self.initial_state,
+            previous: self.previous.clone(),
+            sub_machines: self.sub_machines.clone(),
+            timeouts: self.timeouts.clone(),
+            transitions: self.transitions.clone(),
+            triggers: self.triggers.clone(),
+        }
+    }
 }
 
-impl<TState, TEvent> StateMachineDefinition<TState, TEvent>
+impl<TState, TEvent, TPayload> StateMachineDefinition<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
           TEvent: Copy + Eq + Hash
 {
     /// Creates a new [`FiniteStateMachine`] from the state machine definition
     ///
-    /// _* see the [`state machine`](crate::state_machine) module for an example_
+    /// _* see the [`state machine`](mod@crate::state_machine) module for an example_
     ///
     /// # Results
     ///
     /// Returns an implementation of a [`FiniteStateMachine`]
     ///
-    pub fn create(&self) -> impl FiniteStateMachine<TState, TEvent> {
+    pub fn create(&self) -> impl FiniteStateMachine<TState, TEvent, TPayload> {
         StateMachine::new(self.clone())
     }
+
+    /// Creates a new [`FiniteStateMachine`] from the state machine definition, with
+    /// options, _i.e. a clock, a bounded journal, and the maximum depths of its event
+    /// queue and undo history_
+    ///
+    /// _* a state machine created with_ [`create`] _has the default_ [`StateMachineOptions`]
+    ///
+    /// # Arguments
+    ///
+    /// * _`options`_ - the [`StateMachineOptions`] of the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`FiniteStateMachine`]
+    ///
+    /// [`create`]: StateMachineDefinition::create
+    pub fn create_with(
+        &self,
+        options: StateMachineOptions,
+    ) -> impl FiniteStateMachine<TState, TEvent, TPayload> {
+        StateMachine::with_options(self.clone(), options)
+    }
+
+    /// Replays events on a new [`FiniteStateMachine`] from the state machine definition,
+    /// _i.e. to rebuild a state machine from an event log, or to check that an event
+    /// log is valid for the state machine definition_
+    ///
+    /// _* the first event starts the state machine, triggers receive the default payload_
+    ///
+    /// # Arguments
+    ///
+    /// * _`events`_ - the events to replay, in order
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`FiniteStateMachine`] at the state of the last event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] with the index of the first event that fails
+    ///
+    /// [`StateError`]: crate::state_machine::StateError::ReplayFailed
+    pub fn replay(
+        &self,
+        events: impl IntoIterator<Item=TEvent>,
+    ) -> Result<impl FiniteStateMachine<TState, TEvent, TPayload>, TState, TEvent>
+        where TPayload: Default
+    {
+        let mut machine = StateMachine::new(self.clone());
+
+        machine.replay(events)?;
+
+        Ok(machine)
+    }
+
+    /// Replays events on a new [`FiniteStateMachine`] from the state machine definition,
+    /// without invoking triggers
+    ///
+    /// _* see_ [`replay`](StateMachineDefinition::replay)<br>
+    /// _** actions are part of the state machine definition, they are invoked_<br>
+    /// _*** the triggers of sub state machines are not invoked either_<br>
+    /// _**** the triggers of the state machine definition are invoked by subsequent events_
+    ///
+    /// # Arguments
+    ///
+    /// * _`events`_ - the events to replay, in order
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`FiniteStateMachine`] at the state of the last event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] with the index of the first event that fails
+    ///
+    /// [`StateError`]: crate::state_machine::StateError::ReplayFailed
+    pub fn replay_without_triggers(
+        &self,
+        events: impl IntoIterator<Item=TEvent>,
+    ) -> Result<impl FiniteStateMachine<TState, TEvent, TPayload>, TState, TEvent>
+        where TPayload: Default
+    {
+        let mut machine = StateMachine::with_options(self.clone(), StateMachineOptions {
+            triggers: false,
+            ..StateMachineOptions::new()
+        });
+
+        machine.replay(events)?;
+        machine.enable_triggers();
+
+        Ok(machine)
+    }
+
+    /// Restores a [`FiniteStateMachine`] from a snapshot of a state machine
+    /// created from the state machine definition
+    ///
+    /// _* a snapshot at the initial state, _i.e. of a state machine that was not
+    /// started_, restores a state machine at the initial state_
+    ///
+    /// _* the undo history and sub state machines are not part of a snapshot, a state
+    /// machine restored at a composite state does not have an active sub state machine_
+    ///
+    /// _* the history of the snapshot is not restored, a state machine restored with_
+    /// [`restore`] _does not have a journal, see_ [`restore_with`]
+    ///
+    /// _* the timed transition of the restored state is timed from when it is restored_
+    ///
+    /// # Arguments
+    ///
+    /// * _`snapshot`_ - a [`Snapshot`] of a state machine
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`FiniteStateMachine`] at the state of the snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if the state of the snapshot is not defined
+    ///
+    /// [`restore`]: StateMachineDefinition::restore
+    /// [`restore_with`]: StateMachineDefinition::restore_with
+    /// [`Snapshot`]: crate::state_machine::Snapshot
+    /// [`StateError`]: crate::state_machine::StateError::UndefinedStates
+    #[cfg(feature = "serde")]
+    pub fn restore(
+        &self,
+        snapshot: &Snapshot<TState, TEvent>,
+    ) -> Result<impl FiniteStateMachine<TState, TEvent, TPayload>, TState, TEvent> {
+        self.restore_with(snapshot, StateMachineOptions::new())
+    }
+
+    /// Restores a [`FiniteStateMachine`] from a snapshot of a state machine created
+    /// from the state machine definition, with options, loading the history of the
+    /// snapshot into the journal of the options
+    ///
+    /// _* see_ [`restore`]<br>
+    /// _** the oldest entries of the history are discarded if the journal does not
+    /// have the capacity for all of them_
+    ///
+    /// # Arguments
+    ///
+    /// * _`snapshot`_ - a [`Snapshot`] of a state machine
+    /// * _`options`_ - the [`StateMachineOptions`] of the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`FiniteStateMachine`] at the state of the snapshot
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if the state of the snapshot is not defined
+    ///
+    /// [`restore`]: StateMachineDefinition::restore
+    /// [`Snapshot`]: crate::state_machine::Snapshot
+    /// [`StateError`]: crate::state_machine::StateError::UndefinedStates
+    #[cfg(feature = "serde")]
+    pub fn restore_with(
+        &self,
+        snapshot: &Snapshot<TState, TEvent>,
+        options: StateMachineOptions,
+    ) -> Result<impl FiniteStateMachine<TState, TEvent, TPayload>, TState, TEvent> {
+        let mut machine = StateMachine::with_options(self.clone(), options);
+
+        let undefined_states = std::iter::once(snapshot.current_state)
+            .chain(snapshot.previous_state)
+            .filter(|state| !self.transitions.contains_key(state) && !self.end_states.contains(state))
+            .collect::<Vec<_>>();
+
+        if !undefined_states.is_empty() {
+            return Err(UndefinedStates { states: undefined_states });
+        }
+
+        if snapshot.current_state != self.initial_state {
+            machine.current_state = snapshot.current_state;
+            machine.entered = Some(machine.clock.now());
+            machine.previous_state = snapshot.previous_state;
+        }
+
+        machine.journal.load(snapshot.history.iter().copied());
+
+        Ok(machine)
+    }
+
+    /// Creates a new [`AsyncFiniteStateMachine`] from the state machine definition,
+    /// which awaits its triggers
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of an [`AsyncFiniteStateMachine`]
+    ///
+    /// [`AsyncFiniteStateMachine`]: crate::state_machine::AsyncFiniteStateMachine
+    #[cfg(feature = "async")]
+    pub fn create_async(&self) -> impl AsyncFiniteStateMachine<TState, TEvent, TPayload>
+        where TState: Shareable,
+              TEvent: Shareable,
+              TPayload: Shareable
+    {
+        AsyncStateMachine::new(self.clone())
+    }
+}
+
+impl<TState, TEvent, TPayload> StateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy
+{
+    /// Gets all of the transition edges of the state machine definition,
+    /// including guarded transitions and transitions to the previous state
+    pub(crate) fn edges(&self) -> impl Iterator<Item=(TState, TState)> + '_
+        where TState: Eq + Hash
+    {
+        let previous = self.previous.keys()
+            .flat_map(|state| self.origins(*state).into_iter().map(move |origin| (*state, origin)));
+
+        edges(&self.transitions, &self.guards).chain(previous)
+    }
+
+    /// Gets the states that transition to a state, _i.e. the states a transition
+    /// to the previous state of the state can resolve to_
+    fn origins(&self, state: TState) -> HashSet<TState>
+        where TState: Eq + Hash
+    {
+        edges(&self.transitions, &self.guards)
+            .filter(|(from, next)| *next == state && *from != state)
+            .map(|(from, _)| from)
+            .collect()
+    }
+
+    /// Gets all of the transition edges to the previous state of the state
+    /// machine definition, resolved to each state a transition can resolve to
+    pub(crate) fn previous_transitions(&self) -> impl Iterator<Item=(TState, TEvent, TState)> + '_
+        where TState: Eq + Hash,
+              TEvent: Copy
+    {
+        self.previous.iter()
+            .flat_map(|(state, events)| {
+                let origins = self.origins(*state);
+
+                events.iter()
+                    .flat_map(move |event| origins.clone().into_iter().map(move |origin| (*state, *event, origin)))
+            })
+    }
+}
+
+/// Gets all of the transition edges from a collection of transitions and
+/// guarded transitions
+///
+/// # Arguments
+///
+/// * _`transitions`_ - a reference to a collections of transitions for states
+/// * _`guards`_ - a reference to a collections of guarded transitions for states
+///
+/// # Results
+///
+/// Returns an `Iterator<Item=(TState, TState)>`, which are pairs of
+/// (from, to) transition edges
+pub fn edges<'a, TState, TEvent>(
+    transitions: &'a HashMap<TState, HashMap<TEvent, TState>>,
+    guards: &'a Guards<TState, TEvent>,
+) -> impl Iterator<Item=(TState, TState)> + 'a
+    where TState: Copy
+{
+    let transitions = transitions.iter()
+        .flat_map(|(from, edges)| edges.values().map(move |next| (*from, *next)));
+
+    let guarded = guards.iter()
+        .flat_map(|(from, edges)| edges.values().flatten().map(move |(next, _)| (*from, *next)));
+
+    transitions.chain(guarded)
 }
@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::iter::once;
+
+use crate::state_machine::dense_machine::DenseStateMachine;
+use crate::state_machine::{Callback, FiniteStateMachine, Shared, Triggers};
+use crate::table::Indexed;
+
+const BITS: usize = u64::BITS as usize;
+
+/// Definition of a state machine with dense indices assigned to its states, and its
+/// events indexed by their [`Indexed`] index
+///
+/// Its transitions are a flat table indexed by state and event, its end states are a
+/// bitset and its triggers are stored by state index, _so a transition does not hash
+/// its event, or its current state_
+///
+/// An instance can be initialized with the [`StateMachineBuilder`] and is
+/// instantiated with the [`BuilderState::build_dense`] method.
+///
+/// _* a dense state machine definition does not support actions, deferred events,
+/// guarded, previous state or timed transitions, or sub state machines_
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
+///
+/// [`StateMachineBuilder`]: crate::StateMachineBuilder
+/// [`BuilderState::build_dense`]: crate::builder::BuilderState::build_dense
+/// [`Indexed`]: crate::table::Indexed
+pub struct DenseStateMachineDefinition<TState, TEvent, TPayload = ()> {
+    pub(crate) columns: usize,
+    pub(crate) end_states: Shared<Vec<u64>>,
+    pub(crate) events: Shared<Vec<TEvent>>,
+    pub(crate) state_indices: Shared<HashMap<TState, usize>>,
+    pub(crate) states: Shared<Vec<TState>>,
+    pub(crate) transitions: Shared<Vec<Option<usize>>>,
+    pub(crate) triggers: Shared<Vec<Vec<Callback<TState, TEvent, TPayload>>>>,
+}
+
+// derived `Clone` would needlessly require `TState`, `TEvent` and `TPayload` to be `Clone`
+impl<TState, TEvent, TPayload> Clone for DenseStateMachineDefinition<TState, TEvent, TPayload> {
+    fn clone(&self) -> Self {
+        Self {
+            columns: self.columns,
+            end_states: self.end_states.clone(),
+            events: self.events.clone(),
+            state_indices: self.state_indices.clone(),
+            states: self.states.clone(),
+            transitions: self.transitions.clone(),
+            triggers: self.triggers.clone(),
+        }
+    }
+}
+
+impl<TState, TEvent, TPayload> DenseStateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash + Indexed
+{
+    /// Assigns dense indices to the states of a validated state machine definition,
+    /// the initial state is assigned the index `0`, and indexes its events by their
+    /// [`Indexed`] index
+    ///
+    /// # Arguments
+    ///
+    /// * _`initial_state`_ - the initial state of the state machine definition
+    /// * _`transitions`_ - the transitions of each state
+    /// * _`end_states`_ - the end states
+    /// * _`triggers`_ - the triggers of each state
+    ///
+    /// # Results
+    ///
+    /// Returns a new dense state machine definition
+    pub(crate) fn new(
+        initial_state: TState,
+        transitions: &HashMap<TState, HashMap<TEvent, TState>>,
+        end_states: &HashSet<TState>,
+        triggers: Triggers<TState, TEvent, TPayload>,
+    ) -> Self {
+        let mut states = Vec::new();
+        let mut state_indices = HashMap::new();
+
+        for state in once(&initial_state).chain(transitions.keys()).chain(end_states) {
+            state_indices.entry(*state).or_insert_with(|| {
+                states.push(*state);
+
+                states.len() - 1
+            });
+        }
+
+        let mut events = transitions.values()
+            .flat_map(HashMap::keys)
+            .copied()
+            .collect::<HashSet<_>>().into_iter()
+            .collect::<Vec<_>>();
+
+        events.sort_by_key(|event| event.index());
+
+        let columns = events.last().map_or(0, |event| event.index() + 1);
+        let mut table = vec![None; states.len() * columns];
+
+        for (state, edges) in transitions {
+            for (event, next) in edges {
+                table[state_indices[state] * columns + event.index()] = Some(state_indices[next]);
+            }
+        }
+
+        let mut bitset = vec![0; states.len().div_ceil(BITS)];
+
+        for state in end_states {
+            let index = state_indices[state];
+
+            bitset[index / BITS] |= 1 << (index % BITS);
+        }
+
+        let mut callbacks = states.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+
+        for (state, triggers) in triggers {
+            if let Some(index) = state_indices.get(&state) {
+                callbacks[*index] = triggers;
+            }
+        }
+
+        Self {
+            columns,
+            end_states: Shared::new(bitset),
+            events: Shared::new(events),
+            state_indices: Shared::new(state_indices),
+            states: Shared::new(states),
+            transitions: Shared::new(table),
+            triggers: Shared::new(callbacks),
+        }
+    }
+
+    /// Creates a new [`FiniteStateMachine`] from the dense state machine definition
+    ///
+    /// _* a dense state machine does not record a journal, or keep an undo stack_
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`FiniteStateMachine`]
+    ///
+    #[must_use]
+    pub fn create(&self) -> impl FiniteStateMachine<TState, TEvent, TPayload> {
+        DenseStateMachine::new(self.clone())
+    }
+
+    /// Gets the states of the state machine definition, in the order of their indices,
+    /// _the initial state is first_
+    ///
+    /// # Results
+    ///
+    /// Returns the states of the state machine definition
+    #[must_use]
+    pub fn states(&self) -> &[TState] {
+        &self.states
+    }
+
+    /// Gets the events used by the transitions of the state machine definition, in the
+    /// order of their [`Indexed`] indices
+    ///
+    /// # Results
+    ///
+    /// Returns the events of the state machine definition
+    #[must_use]
+    pub fn events(&self) -> &[TEvent] {
+        &self.events
+    }
+}
+
+impl<TState, TEvent, TPayload> DenseStateMachineDefinition<TState, TEvent, TPayload> {
+    /// Indicates if the state of an index is an end state
+    pub(crate) fn is_end(&self, state: usize) -> bool {
+        self.end_states[state / BITS] & (1 << (state % BITS)) != 0
+    }
+
+    /// Looks up the index of the next state of a transition from the state of an index,
+    /// on the event of an index, _an event index out of bounds does not transition_
+    pub(crate) fn next(&self, state: usize, event: usize) -> Option<usize> {
+        if event < self.columns {
+            self.transitions[state * self.columns + event]
+        } else {
+            None
+        }
+    }
+}
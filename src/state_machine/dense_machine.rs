@@ -0,0 +1,238 @@
+use std::hash::Hash;
+use std::ops::Deref;
+
+use crate::state_machine::{Callback, DenseStateMachineDefinition, EventQueue, Result, Shared};
+use crate::state_machine::fsm::FiniteStateMachine;
+#[cfg(feature = "async")]
+use crate::state_machine::StateError::AsyncTrigger;
+use crate::state_machine::StateError::{
+    AlreadyStarted, EndState, InvalidTransition, NotAStartEvent, NotStarted, QueueOverflow,
+    TriggerFailed, UndefinedStates
+};
+use crate::{FallibleTrigger, Trigger};
+use crate::table::Indexed;
+
+const CUSTOM_TRIGGERS_NOT_SHARED: &str = "custom triggers should not be shared";
+const DEFAULT_MAX_DEPTH: usize = 64;
+const INITIAL_STATE: usize = 0;
+
+// DenseStateMachineDefinition built implementation of a FiniteStateMachine trait object,
+// which tracks the index of its current state
+pub struct DenseStateMachine<TState, TEvent, TPayload> {
+    pub(crate) current_state: usize,
+    pub(crate) custom_triggers: bool,
+    pub(crate) definition: DenseStateMachineDefinition<TState, TEvent, TPayload>,
+    pub(crate) max_depth: usize,
+    pub(crate) previous_state: Option<usize>,
+}
+
+impl<TState, TEvent, TPayload> DenseStateMachine<TState, TEvent, TPayload> {
+    /// Initializes a new instance of a dense state machine, at the initial state
+    ///
+    /// # Arguments
+    ///
+    /// * _`definition`_ - an instance of a dense state machine definition
+    ///
+    /// # Results
+    ///
+    /// Returns a new initialized instance of a dense state machine
+    pub const fn new(definition: DenseStateMachineDefinition<TState, TEvent, TPayload>) -> Self {
+        Self {
+            current_state: INITIAL_STATE,
+            custom_triggers: false,
+            definition,
+            max_depth: DEFAULT_MAX_DEPTH,
+            previous_state: None,
+        }
+    }
+}
+
+impl<TState, TEvent, TPayload> Deref for DenseStateMachine<TState, TEvent, TPayload> {
+    type Target = TState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.definition.states[self.current_state]
+    }
+}
+
+// a dense state machine does not record a journal, keep an undo stack, or time its states
+impl<TState, TEvent, TPayload> FiniteStateMachine<TState, TEvent, TPayload>
+    for DenseStateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash + Indexed
+{
+    fn clear_triggers(&mut self) {
+        self.custom_triggers = true;
+        self.definition.triggers = Shared::new(self.definition.states.iter().map(|_| Vec::new()).collect());
+    }
+
+    fn current_state(&self) -> TState {
+        self.definition.states[self.current_state]
+    }
+
+    fn event_with(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        if !self.is_started() {
+            return Err(NotStarted);
+        }
+
+        self.process(event, &payload)
+    }
+
+    fn is_end(&self) -> bool {
+        self.definition.is_end(self.current_state)
+    }
+
+    fn is_started(&self) -> bool {
+        self.current_state != INITIAL_STATE
+    }
+
+    fn new_fallible_triggers(
+        &mut self,
+        triggers: Vec<(TState, Vec<FallibleTrigger<TState, TEvent>>)>
+    ) -> Result<(), TState, TEvent> {
+        self.merge_triggers(triggers, Callback::Fallible)
+    }
+
+    fn new_triggers(
+        &mut self,
+        triggers: Vec<(TState, Vec<Trigger<TState, TEvent>>)>
+    ) -> Result<(), TState, TEvent> {
+        self.merge_triggers(triggers, Callback::Trigger)
+    }
+
+    fn next_states<'a>(&'a self) -> Box<dyn Iterator<Item=(&'a TEvent, &'a TState)> + 'a> {
+        Box::new(
+            self.definition.events.iter()
+                .filter_map(|event| self.definition.next(self.current_state, event.index())
+                    .map(|next| (event, &self.definition.states[next])))
+        )
+    }
+
+    fn previous_state(&self) -> Option<TState> {
+        self.previous_state.map(|previous| self.definition.states[previous])
+    }
+
+    fn reset(&mut self) -> TState {
+        let last = self.current_state();
+
+        self.current_state = INITIAL_STATE;
+        self.previous_state = None;
+
+        last
+    }
+
+    fn start_with(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        if self.is_started() {
+            return Err(AlreadyStarted { current_state: self.current_state() });
+        }
+
+        if self.definition.next(INITIAL_STATE, event.index()).is_none() {
+            return Err(NotAStartEvent { event });
+        }
+
+        self.process(event, &payload)
+    }
+}
+
+impl<TState, TEvent, TPayload> DenseStateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash + Indexed
+{
+    /// Transitions on an event, then on the follow-up events posted by queued
+    /// triggers, in order, until the event queue is empty
+    ///
+    /// _a failed follow-up event discards the remaining follow-up events_
+    fn process(&mut self, event: TEvent, payload: &TPayload) -> Result<TState, TState, TEvent> {
+        let mut queue = EventQueue::new();
+        let mut transition = self.transition_on(event, payload, &mut queue)?;
+
+        for depth in 0.. {
+            let Some((event, payload)) = queue.next() else { break };
+
+            if depth == self.max_depth {
+                return Err(QueueOverflow { event, max_depth: self.max_depth });
+            }
+
+            transition = self.transition_on(event, &payload, &mut queue)?;
+        }
+
+        Ok(transition)
+    }
+
+    /// Merges triggers into the triggers of this instance of the state machine,
+    /// wrapping each trigger as a [`Callback`]
+    ///
+    /// _the first triggers of an instance replace the triggers of the state machine
+    /// definition, subsequent triggers are added to the triggers of their states_
+    fn merge_triggers<TTrigger>(
+        &mut self,
+        triggers: Vec<(TState, Vec<TTrigger>)>,
+        callback: impl Fn(TTrigger) -> Callback<TState, TEvent, TPayload>,
+    ) -> Result<(), TState, TEvent> {
+        let undefined_states = triggers.iter()
+            .filter(|(state, _)| !self.definition.state_indices.contains_key(state))
+            .map(|(state, _)| *state)
+            .collect::<Vec<TState>>();
+
+        if !undefined_states.is_empty() {
+            return Err(UndefinedStates {
+                states: undefined_states
+            });
+        }
+
+        if !self.custom_triggers {
+            self.clear_triggers();
+        }
+
+        let indices = &self.definition.state_indices;
+        let existing = Shared::get_mut(&mut self.definition.triggers).expect(CUSTOM_TRIGGERS_NOT_SHARED);
+
+        for (state, triggers) in triggers {
+            existing[indices[&state]].extend(triggers.into_iter().map(&callback));
+        }
+
+        Ok(())
+    }
+
+    /// Transitions to the next state of an event, looked up by the index of the
+    /// current state and the index of the event
+    ///
+    /// _the current state is only committed when all triggers succeed, a failed
+    /// trigger vetoes the transition and the remaining triggers are not invoked_
+    fn transition_on(
+        &mut self,
+        event: TEvent,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        let current_state = self.current_state();
+
+        if self.is_end() {
+            return Err(EndState { end: current_state });
+        }
+
+        let next = self.definition.next(self.current_state, event.index())
+            .ok_or(InvalidTransition { event, current_state })?;
+
+        let transition = self.definition.states[next];
+        let triggers = &self.definition.triggers[next];
+
+        #[cfg(feature = "async")]
+        if triggers.iter().any(Callback::is_async) {
+            return Err(AsyncTrigger { state: transition, event });
+        }
+
+        for trigger in triggers {
+            trigger.invoke(event, current_state, transition, payload, queue)
+                .map_err(|source| TriggerFailed { state: transition, event, source })?;
+        }
+
+        if next != self.current_state {
+            self.previous_state = Some(self.current_state);
+        }
+
+        self.current_state = next;
+
+        Ok(transition)
+    }
+}
@@ -1,4 +1,3 @@
-use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 use crate::graph::Graph;
@@ -65,38 +64,34 @@ use crate::state_machine::StateMachineDefinition;
 /// [cycles]: https://en.wikipedia.org/wiki/Cycle_(graph_theory)
 /// [`FiniteStateMachine::has_cycles`]: crate::FiniteStateMachine::has_cycles
 #[inline]
-pub fn detect_cycles<TState, TEvent>(fsm: &StateMachineDefinition<TState, TEvent>) -> bool
+pub fn detect_cycles<TState, TEvent, TPayload>(
+    fsm: &StateMachineDefinition<TState, TEvent, TPayload>
+) -> bool
     where TState: Copy + Eq + Hash
 {
-    detect_cycles_impl(&fsm.transitions, &fsm.end_states)
+    detect_cycles_impl(fsm.transitions.keys().chain(fsm.end_states.iter()).copied(), fsm.edges())
 }
 
 /// Internal implementation of [`detect_cycles`]
 ///
-/// todo - make generic over iterators
-///
 /// ### Arguments
 ///
-/// * _`transitions`_ - a reference to a collections of transitions for states
-/// * _`end_states`_ - a reference to a collection of end states
+/// * _`nodes`_ - all of the states
+/// * _`edges`_ - all of the (from, to) transition edges between states
 ///
 /// ### Returns
 ///
 /// Returns `true` if a cycle was detected, `false` otherwise
-pub fn detect_cycles_impl<TState, TEvent>(
-    transitions: &HashMap<TState, HashMap<TEvent, TState>>,
-    end_states: &HashSet<TState>,
+pub fn detect_cycles_impl<TState>(
+    nodes: impl IntoIterator<Item=TState>,
+    edges: impl IntoIterator<Item=(TState, TState)>,
 ) -> bool
     where TState: Copy + Eq + Hash
 {
-    let mut graph = <Graph<TState>>::new(transitions.keys().chain(end_states.iter()).copied());
-
-    for node in transitions.keys().copied() {
-        let edges = transitions.get(&node).expect("every node requires a transition");
+    let mut graph = <Graph<TState>>::new(nodes);
 
-        for (_, edge) in edges.iter() {
-            graph.add_edge(node, *edge);
-        }
+    for (from, to) in edges {
+        graph.add_edge(from, to);
     }
 
     graph.is_cyclical()
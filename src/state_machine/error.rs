@@ -2,6 +2,8 @@ use std::fmt::{Debug, Display, Formatter};
 
 use thiserror::Error;
 
+use crate::TriggerError;
+
 /// The [`Error`] type for [`FiniteStateMachine`] operations;
 /// _[`start`]_, _and [`event`]_
 ///
@@ -20,6 +22,20 @@ pub enum StateError<TState, TEvent> {
         current_state: TState,
     },
 
+    /// Occurs when a transition of a [`FiniteStateMachine`] would
+    /// invoke an async trigger, which can only be awaited by an
+    /// `AsyncFiniteStateMachine`, _with the `async` feature_; the
+    /// transition is not taken
+    ///
+    /// [`FiniteStateMachine`]: crate::FiniteStateMachine
+    AsyncTrigger {
+        /// the state the machine was transitioning to
+        state: TState,
+
+        /// the event of the transition
+        event: TEvent,
+    },
+
     /// Occurs when an [`event`] operation is attempted on a
     /// state machine that has ended
     ///
@@ -29,6 +45,18 @@ pub enum StateError<TState, TEvent> {
         end: TState
     },
 
+    /// Occurs when none of the guards of the guarded transitions
+    /// of an [`event`], for the current state, permit the transition
+    ///
+    /// [`event`]: crate::FiniteStateMachine::event
+    GuardRejected {
+        /// the guarded event
+        event: TEvent,
+
+        /// the current state of the machine
+        current_state: TState,
+    },
+
     /// Occurs when an undefined [`event`] operation, for the
     /// current state, is attempted on a state machine
     ///
@@ -50,12 +78,65 @@ pub enum StateError<TState, TEvent> {
         event: TEvent
     },
 
+    /// Occurs when an [`undo`] operation is attempted on a state
+    /// machine that has not transitioned, _or was reset_
+    ///
+    /// [`undo`]: crate::FiniteStateMachine::undo
+    NothingToUndo,
+
     /// Occurs when an [`event`] operation is attempted on a
     /// state machine that has not been started
     ///
     /// [`event`]: crate::FiniteStateMachine::event
     NotStarted,
 
+    /// Occurs when the follow-up events posted by queued triggers exceed the
+    /// maximum depth of the event queue, _i.e. an infinite loop of follow-up
+    /// events_; the transitions of the processed events are not undone, and
+    /// the remaining follow-up events are discarded
+    QueueOverflow {
+        /// the follow-up event that exceeded the maximum depth
+        event: TEvent,
+
+        /// the maximum depth of the event queue
+        max_depth: usize,
+    },
+
+    /// Occurs when an event of a [`replay`] fails; the index of the
+    /// start event is `0`
+    ///
+    /// [`replay`]: crate::StateMachineDefinition::replay
+    ReplayFailed {
+        /// the index of the first event that failed
+        index: usize,
+
+        /// the error of the failed event
+        #[allow(clippy::use_self)] // thiserror infers the bounds of the source from its type
+        source: Box<StateError<TState, TEvent>>,
+    },
+
+    /// Occurs when new triggers are defined for a [`TableStateMachine`],
+    /// whose triggers are the function pointers of its [`StateTable`]
+    ///
+    /// [`StateTable`]: crate::table::StateTable
+    /// [`TableStateMachine`]: crate::table::TableStateMachine
+    StaticTriggers,
+
+    /// Occurs when a [`FallibleTrigger`] fails, vetoing the
+    /// transition; the current state of the machine is unchanged
+    ///
+    /// [`FallibleTrigger`]: crate::FallibleTrigger
+    TriggerFailed {
+        /// the state the machine was transitioning to
+        state: TState,
+
+        /// the event of the vetoed transition
+        event: TEvent,
+
+        /// the error of the failed trigger
+        source: TriggerError,
+    },
+
     /// Occurs when new triggers are defined for undefined
     /// states
     UndefinedStates {
@@ -75,18 +156,39 @@ impl<TState, TEvent> Display for StateError<TState, TEvent>
                     "State machine is already started; current state {current_state}"
                 )),
 
+            Self::AsyncTrigger { state, event } =>
+                fmt.write_fmt(format_args!("Triggers of {state} on {event} can only be awaited")),
+
             Self::EndState { end } =>
                 fmt.write_fmt(format_args!("Reached end state {end}")),
 
+            Self::GuardRejected { event, current_state } =>
+                fmt.write_fmt(format_args!("Guards do not permit transition from {current_state} on {event}")),
+
             Self::InvalidTransition { event, current_state } =>
                 fmt.write_fmt(format_args!("Can not transition from {current_state} on {event}")),
 
             Self::NotAStartEvent { event } =>
                 fmt.write_fmt(format_args!("{event} is not a starting event")),
 
+            Self::NothingToUndo =>
+                fmt.write_fmt(format_args!("State machine has no transitions to undo")),
+
             Self::NotStarted =>
                 fmt.write_fmt(format_args!("State machine is not started")),
 
+            Self::QueueOverflow { event, max_depth } =>
+                fmt.write_fmt(format_args!("Follow-up event {event} exceeds the maximum queue depth {max_depth}")),
+
+            Self::ReplayFailed { index, source } =>
+                fmt.write_fmt(format_args!("Replay failed on event {index}; {source}")),
+
+            Self::StaticTriggers =>
+                fmt.write_fmt(format_args!("Triggers of a state table can not be redefined")),
+
+            Self::TriggerFailed { state, event, source } =>
+                fmt.write_fmt(format_args!("Trigger of {state} failed on {event}; {source}")),
+
             Self::UndefinedStates { states } =>
                 fmt.write_fmt(format_args!("{states:?} are not defined states"))
         }
@@ -1,6 +1,8 @@
 use std::ops::Deref;
+use std::time::Instant;
 
-use crate::{state_machine, Trigger};
+use crate::{state_machine, FallibleTrigger, Trigger};
+use crate::state_machine::JournalEntry;
 
 /// _`FiniteStateMachine<TState, TEvent>`_ is a trait representing the
 /// api of a [state machine]
@@ -9,6 +11,8 @@ use crate::{state_machine, Trigger};
 ///
 /// * _`TState`_ - represents the states of a state machine
 /// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
 ///
 /// ### `Deref<Target=TState>`
 ///
@@ -16,7 +20,17 @@ use crate::{state_machine, Trigger};
 /// de-referencing to the current state
 ///
 /// [state machine]: https://en.wikipedia.org/wiki/Finite-state_machine
-pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
+pub trait FiniteStateMachine<TState, TEvent, TPayload = ()>: Deref<Target=TState> {
+    /// Gets the journals archived when the state machine was reset, if the
+    /// journal is configured to be archived
+    ///
+    /// # Results
+    ///
+    /// Returns the archived journals, oldest first, _empty by default_
+    fn archived_history(&self) -> Vec<Vec<JournalEntry<TState, TEvent>>> {
+        Vec::new()
+    }
+
     /// Clears all triggers for this instance of a state machine
     ///
     /// _* does not impact the triggers of the shared_ [`StateMachineDefinition`]
@@ -25,13 +39,37 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
     /// [`StateMachineDefinition`]: crate::StateMachineDefinition
     fn clear_triggers(&mut self);
 
+    /// Gets the active path of states of the state machine, starting with the
+    /// current state and followed by the current states of any active sub
+    /// state machines
+    ///
+    /// # Results
+    ///
+    /// Returns the current state, followed by the current states of any
+    /// active sub state machines
+    fn current_path(&self) -> Vec<TState> {
+        vec![self.current_state()]
+    }
+
     /// Gets the current state of the state machine
     ///
     fn current_state(&self) -> TState;
 
+    /// Gets the deferred events held by the state machine, waiting to be retried
+    /// after the next successful transition
+    ///
+    /// # Results
+    ///
+    /// Returns the held events, in the order they are retried, _empty by default_
+    fn deferred_events(&self) -> Vec<TEvent> {
+        Vec::new()
+    }
+
     /// Transitions the state machine from the current state to the next
     /// state based on the defined event transition edge
     ///
+    /// _* triggers receive the default payload_
+    ///
     /// # Arguments
     ///
     /// * _`event`_ - event triggering the transition
@@ -47,7 +85,37 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
     /// Returns a [`StateError`] if there are any errors transitioning on event
     ///
     /// [`StateError`]: state_machine::StateError
-    fn event(&mut self, event: TEvent) -> state_machine::Result<TState, TState, TEvent>;
+    fn event(&mut self, event: TEvent) -> state_machine::Result<TState, TState, TEvent>
+        where TPayload: Default
+    {
+        self.event_with(event, TPayload::default())
+    }
+
+    /// Transitions the state machine from the current state to the next
+    /// state based on the defined event transition edge, delivering a
+    /// payload to the triggers of the transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event triggering the transition
+    /// * _`payload`_ - payload delivered to the triggers
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine after the transition event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors transitioning on event
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn event_with(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+    ) -> state_machine::Result<TState, TState, TEvent>;
 
     /// Indicates if the state machine has [cycles]
     ///
@@ -61,6 +129,22 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
         None
     }
 
+    /// Gets the journal of the transitions of the state machine, which is only
+    /// recorded by a state machine created with a journal
+    ///
+    /// _* the journal is bounded by its capacity, the oldest entries are
+    /// discarded once the journal is full_
+    ///
+    /// # Results
+    ///
+    /// Returns the [`JournalEntry`] of each recorded transition, oldest first,
+    /// _empty by default_
+    ///
+    /// [`JournalEntry`]: JournalEntry
+    fn history(&self) -> Vec<JournalEntry<TState, TEvent>> {
+        Vec::new()
+    }
+
     /// Checks if the current state of the state machine is an end state
     ///
     /// # Results
@@ -75,13 +159,44 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
     /// Returns `true` if the state machine has started, `false` otherwise
     fn is_started(&self) -> bool;
 
+    /// Defines new fallible triggers for transitions of a collection of states,
+    /// a failed trigger vetoes the transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`triggers`_ - a collection of (state, [`FallibleTrigger`] collection) pairs
+    ///
+    /// _* the first triggers defined for an instance override the triggers of the state
+    /// machine definition, triggers defined afterwards, with either_ [`new_triggers`]
+    /// _or_ [`new_fallible_triggers`]_, are added to the triggers of their states_<br>
+    /// _** does not impact the triggers of the shared_ [`StateMachineDefinition`]
+    /// _instance_
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any triggers defined for undefined states
+    ///
+    /// _* see_ [`StateError`] _for more details_
+    ///
+    /// [`StateError`]: state_machine::StateError::UndefinedStates
+    /// [`FallibleTrigger`]: FallibleTrigger
+    /// [`new_fallible_triggers`]: FiniteStateMachine::new_fallible_triggers
+    /// [`new_triggers`]: FiniteStateMachine::new_triggers
+    /// [`StateMachineDefinition`]: crate::StateMachineDefinition
+    fn new_fallible_triggers(
+        &mut self,
+        triggers: Vec<(TState, Vec<FallibleTrigger<TState, TEvent>>)>
+    ) -> state_machine::Result<(), TState, TEvent>;
+
     /// Defines new triggers for transitions of a collection of states
     ///
     /// # Arguments
     ///
     /// * _`triggers`_ - a collection of (state, [`Trigger`] collection) pairs
     ///
-    /// _* overrides the current existing triggers, if any exist_<br>
+    /// _* the first triggers defined for an instance override the triggers of the state
+    /// machine definition, triggers defined afterwards, with either_ [`new_triggers`]
+    /// _or_ [`new_fallible_triggers`]_, are added to the triggers of their states_<br>
     /// _** does not impact the triggers of the shared_ [`StateMachineDefinition`]
     /// _instance_
     ///
@@ -93,6 +208,8 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
     ///
     /// [`StateError`]: state_machine::StateError::UndefinedStates
     /// [`Trigger`]: Trigger
+    /// [`new_fallible_triggers`]: FiniteStateMachine::new_fallible_triggers
+    /// [`new_triggers`]: FiniteStateMachine::new_triggers
     /// [`StateMachineDefinition`]: crate::StateMachineDefinition
     fn new_triggers(
         &mut self,
@@ -105,17 +222,66 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
     ///
     /// Returns an `Iterator<Item=(&'a TEvent, &'a TState)>`, which are pairs of event
     /// transition edges for the current state
+    ///
+    /// _* deferred events are not transition edges, see_
+    /// [`deferred_events`](FiniteStateMachine::deferred_events)
     fn next_states<'a>(&'a self) -> Box<dyn Iterator<Item=(&'a TEvent, &'a TState)> + 'a>;
 
-    /// Resets the state machine to it's initial state
+    /// Gets the deadline of the timed transition of the current state, _or of the
+    /// current states of any active sub state machines_, which is fired by [`tick`]
+    ///
+    /// # Results
+    ///
+    /// Returns the earliest deadline, or `None` if the current states do not have
+    /// timed transitions, _default behavior_
+    ///
+    /// [`tick`]: FiniteStateMachine::tick
+    fn next_deadline(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Gets the previous state of the state machine, _i.e. the state the state
+    /// machine was in before it entered the current state_
+    ///
+    /// _* transitions that re-enter the current state do not change the
+    /// previous state_
+    ///
+    /// # Results
+    ///
+    /// Returns the previous state, or `None` if the state machine has not
+    /// transitioned
+    fn previous_state(&self) -> Option<TState>;
+
+    /// Resets the state machine to it's initial state, clearing or archiving
+    /// the journal
     ///
     /// # Results
     ///
     /// Returns the prior state of the state machine before it was reset
     fn reset(&mut self) -> TState;
 
+    /// Takes a serializable snapshot of the state machine, which can be used to
+    /// [`restore`] the state machine at its current state
+    ///
+    /// # Results
+    ///
+    /// Returns a [`Snapshot`] of the state machine
+    ///
+    /// [`restore`]: crate::StateMachineDefinition::restore
+    /// [`Snapshot`]: state_machine::Snapshot
+    #[cfg(feature = "serde")]
+    fn snapshot(&self) -> state_machine::Snapshot<TState, TEvent> {
+        state_machine::Snapshot {
+            current_state: self.current_state(),
+            history: self.history(),
+            previous_state: self.previous_state(),
+        }
+    }
+
     /// Starts the state machine
     ///
+    /// _* triggers receive the default payload_
+    ///
     /// # Results
     ///
     /// Returns the new state of the state machine
@@ -127,5 +293,89 @@ pub trait FiniteStateMachine<TState, TEvent>: Deref<Target=TState> {
     /// _* see_ [`StateError`] _for details on the possible errors that can occur_
     ///
     /// [`StateError`]: state_machine::StateError
-    fn start(&mut self, event: TEvent) -> state_machine::Result<TState, TState, TEvent>;
+    fn start(&mut self, event: TEvent) -> state_machine::Result<TState, TState, TEvent>
+        where TPayload: Default
+    {
+        self.start_with(event, TPayload::default())
+    }
+
+    /// Starts the state machine, delivering a payload to the triggers
+    /// of the start state
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event starting the state machine
+    /// * _`payload`_ - payload delivered to the triggers
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors starting the state machine
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn start_with(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+    ) -> state_machine::Result<TState, TState, TEvent>;
+
+    /// Fires the timed transition with the earliest deadline, if it has expired by
+    /// the current time of the [`Clock`] of the state machine, as a normal transition
+    ///
+    /// _* triggers receive the default payload_<br>
+    /// _** only one timed transition is fired, the deadline of the newly entered state
+    /// starts when the state is entered_<br>
+    /// _*** the timed transition is fired on the state machine that defines it, an
+    /// active sub state machine does not handle the timeout of a composite state_
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine if a timed transition was fired,
+    /// otherwise `None`, _default behavior_
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors transitioning on the event
+    /// of the timed transition
+    ///
+    /// [`Clock`]: state_machine::Clock
+    /// [`StateError`]: state_machine::StateError
+    fn tick(&mut self) -> state_machine::Result<Option<TState>, TState, TEvent>
+        where TPayload: Default
+    {
+        Ok(None)
+    }
+
+    /// Undoes the last transition of the state machine, invoking the compensating
+    /// actions of the undone state, and restoring the state prior to the transition
+    ///
+    /// _* the transitions of an active sub state machine are undone first, undoing
+    /// the transition into a composite state restores the sub state machine of the
+    /// prior state as it was before the transition_<br>
+    /// _** the undone transition is recorded in the journal, undoing the start
+    /// transition resets the state machine, the same as_
+    /// [`reset`](FiniteStateMachine::reset)_, and then records the undone transition
+    /// in the cleared journal_<br>
+    /// _*** deferred events are discarded, they are not retried in the restored state_<br>
+    /// _**** only the last transitions, up to the undo depth of the state machine, can
+    /// be undone, the oldest transitions are discarded once the limit is reached_
+    ///
+    /// # Results
+    ///
+    /// Returns the restored state of the state machine
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are no transitions to undo, _which is
+    /// the default behavior_
+    ///
+    /// [`StateError`]: state_machine::StateError::NothingToUndo
+    fn undo(&mut self) -> state_machine::Result<TState, TState, TEvent> {
+        Err(state_machine::StateError::NothingToUndo)
+    }
 }
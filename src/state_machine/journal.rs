@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+/// Options of the bounded journal of transitions of a [`FiniteStateMachine`]
+///
+/// [`FiniteStateMachine`]: crate::FiniteStateMachine
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct JournalOptions {
+    /// indicates if the journal is archived when the state machine is reset,
+    /// _otherwise the journal is cleared_
+    pub archive: bool,
+
+    /// the maximum number of journal entries, the oldest entries are discarded
+    /// once the journal is full, _a capacity of `0` disables the journal_
+    pub capacity: usize,
+}
+
+/// An entry of the journal of transitions of a [`FiniteStateMachine`]
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+///
+/// [`FiniteStateMachine`]: crate::FiniteStateMachine
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JournalEntry<TState, TEvent> {
+    /// the event that transitioned the state machine
+    pub event: TEvent,
+
+    /// the state prior to the transition
+    pub from: TState,
+
+    /// the sequence number of the transition, which increases with each
+    /// transition of the state machine, including transitions before a reset
+    pub sequence: u64,
+
+    /// the state after the transition
+    pub to: TState,
+
+    /// indicates if the entry records an undone transition, _i.e. a step back
+    /// from the undone state to the restored state_
+    pub undone: bool,
+}
+
+// Bounded journal of the transitions of a state machine
+pub struct Journal<TState, TEvent> {
+    archive: Vec<Vec<JournalEntry<TState, TEvent>>>,
+    entries: VecDeque<JournalEntry<TState, TEvent>>,
+    options: JournalOptions,
+    sequence: u64,
+}
+
+impl<TState, TEvent> Journal<TState, TEvent> {
+    /// Initializes a new instance of a journal
+    ///
+    /// # Arguments
+    ///
+    /// * _`options`_ - the options of the journal
+    ///
+    /// # Results
+    ///
+    /// Returns a new empty journal
+    pub const fn new(options: JournalOptions) -> Self {
+        Self {
+            archive: Vec::new(),
+            entries: VecDeque::new(),
+            options,
+            sequence: 0,
+        }
+    }
+
+    /// Gets the archived journals, in the order they were archived
+    pub fn archived(&self) -> &[Vec<JournalEntry<TState, TEvent>>] {
+        &self.archive
+    }
+
+    /// Gets the entries of the journal, oldest first
+    pub fn entries(&self) -> impl Iterator<Item=&JournalEntry<TState, TEvent>> {
+        self.entries.iter()
+    }
+
+    /// Loads the entries of a restored journal, the sequence continues after the
+    /// sequence of the last entry
+    #[cfg(feature = "serde")]
+    pub fn load(&mut self, entries: impl IntoIterator<Item=JournalEntry<TState, TEvent>>) {
+        for entry in entries {
+            self.sequence = entry.sequence;
+            self.push(entry);
+        }
+    }
+
+    /// Records a transition, discarding the oldest entry if the journal is full
+    pub fn record(&mut self, event: TEvent, from: TState, to: TState) {
+        self.push(JournalEntry { event, from, sequence: self.sequence, to, undone: false });
+    }
+
+    /// Records an undone transition, from the undone state to the restored state,
+    /// discarding the oldest entry if the journal is full
+    pub fn record_undo(&mut self, event: TEvent, undone: TState, restored: TState) {
+        self.push(JournalEntry { event, from: undone, sequence: self.sequence, to: restored, undone: true });
+    }
+
+    fn push(&mut self, entry: JournalEntry<TState, TEvent>) {
+        if self.options.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() == self.options.capacity {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(entry);
+
+        self.sequence += 1;
+    }
+
+    /// Clears the journal, archiving its entries if the journal is archived
+    pub fn reset(&mut self) {
+        let entries = std::mem::take(&mut self.entries);
+
+        if self.options.archive && !entries.is_empty() {
+            self.archive.push(entries.into());
+        }
+    }
+}
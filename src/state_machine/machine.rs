@@ -1,26 +1,55 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::hash::Hash;
-use std::iter::empty;
+use std::iter::{empty, once};
 use std::ops::Deref;
-use std::rc::Rc;
+use std::time::Instant;
 
-use crate::state_machine::{Result, StateMachineDefinition};
+use crate::state_machine::{
+    Clock, EventQueue, JournalEntry, Result, StateMachineDefinition, StateMachineOptions
+};
+use crate::state_machine::journal::Journal;
 use crate::state_machine::fsm::FiniteStateMachine;
+#[cfg(feature = "async")]
+use crate::state_machine::StateError::AsyncTrigger;
 use crate::state_machine::StateError::{
-    AlreadyStarted, EndState, InvalidTransition, NotAStartEvent, NotStarted, UndefinedStates
+    AlreadyStarted, EndState, GuardRejected, InvalidTransition, NotAStartEvent, NotStarted, NothingToUndo,
+    QueueOverflow, ReplayFailed, TriggerFailed, UndefinedStates
 };
-use crate::Trigger;
+use crate::state_machine::{Callback, Shared};
+use crate::{FallibleTrigger, Trigger};
 
 const ALL_STATES_WITH_TRANSITIONS: &str = "all states should have defined transitions";
+const CUSTOM_TRIGGERS_NOT_SHARED: &str = "custom triggers should not be shared";
 
 // StateMachineBuilder built implementation of a FiniteStateMachine trait object
-pub struct StateMachine<TState, TEvent> {
+pub struct StateMachine<TState, TEvent, TPayload> {
+    pub(crate) child: Option<Box<Self>>,
+    pub(crate) clock: Shared<dyn Clock>,
     pub(crate) current_state: TState,
+    pub(crate) custom_triggers: bool,
+    pub(crate) deferred: Vec<(TEvent, TPayload)>,
+    pub(crate) entered: Option<Instant>,
     pub(crate) has_cycle: Option<bool>,
-    pub(crate) definition: StateMachineDefinition<TState, TEvent>,
+    pub(crate) journal: Journal<TState, TEvent>,
+    pub(crate) max_depth: usize,
+    pub(crate) previous_state: Option<TState>,
+    pub(crate) triggers: bool,
+    pub(crate) undo_depth: usize,
+    pub(crate) undo_stack: VecDeque<Step<TState, TEvent, TPayload>>,
+    pub(crate) definition: StateMachineDefinition<TState, TEvent, TPayload>,
+}
+
+// A committed transition of a state machine, which restores the state machine
+// as it was before the transition when it is undone
+pub struct Step<TState, TEvent, TPayload> {
+    pub(crate) child: Option<Box<StateMachine<TState, TEvent, TPayload>>>,
+    pub(crate) event: TEvent,
+    pub(crate) from: TState,
+    pub(crate) previous_state: Option<TState>,
+    pub(crate) to: TState,
 }
 
-impl<TState, TEvent> StateMachine<TState, TEvent>
+impl<TState, TEvent, TPayload> StateMachine<TState, TEvent, TPayload>
     where TState: Copy
 {
     /// Initializes a new instance of a state machine
@@ -32,16 +61,45 @@ impl<TState, TEvent> StateMachine<TState, TEvent>
     /// # Results
     ///
     /// Returns a new initialized instance of a state machine
-    pub const fn new(definition: StateMachineDefinition<TState, TEvent>) -> Self {
+    pub fn new(definition: StateMachineDefinition<TState, TEvent, TPayload>) -> Self {
+        Self::with_options(definition, StateMachineOptions::new())
+    }
+
+    /// Initializes a new instance of a state machine with options, _i.e. its clock,
+    /// journal, and the maximum depths of its event queue and undo history_
+    ///
+    /// # Arguments
+    ///
+    /// * _`definition`_ - an instance of state machine definition
+    /// * _`options`_ - the options of the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns a new initialized instance of a state machine
+    pub fn with_options(
+        definition: StateMachineDefinition<TState, TEvent, TPayload>,
+        options: StateMachineOptions,
+    ) -> Self {
         Self {
+            child: None,
+            clock: options.clock,
             current_state: definition.initial_state,
+            custom_triggers: false,
+            deferred: Vec::new(),
+            entered: None,
             has_cycle: None,
+            journal: Journal::new(options.journal),
+            max_depth: options.max_depth,
+            previous_state: None,
+            triggers: options.triggers,
+            undo_depth: options.undo_depth,
+            undo_stack: VecDeque::new(),
             definition,
         }
     }
 }
 
-impl<TState, TEvent> Deref for StateMachine<TState, TEvent> {
+impl<TState, TEvent, TPayload> Deref for StateMachine<TState, TEvent, TPayload> {
     type Target = TState;
 
     fn deref(&self) -> &Self::Target {
@@ -49,32 +107,51 @@ impl<TState, TEvent> Deref for StateMachine<TState, TEvent> {
     }
 }
 
-impl<TState, TEvent> FiniteStateMachine<TState, TEvent> for StateMachine<TState, TEvent>
+impl<TState, TEvent, TPayload> FiniteStateMachine<TState, TEvent, TPayload>
+    for StateMachine<TState, TEvent, TPayload>
     where TState: Copy + Eq + Hash,
           TEvent: Copy + Eq + Hash
 {
     fn clear_triggers(&mut self) {
-        self.definition.triggers = Rc::new(HashMap::default());
+        self.custom_triggers = true;
+        self.definition.triggers = Shared::new(HashMap::default());
+    }
+
+    fn current_path(&self) -> Vec<TState> {
+        once(self.current_state)
+            .chain(self.child.iter().flat_map(|child| child.current_path()))
+            .collect()
     }
 
     fn current_state(&self) -> TState {
         self.current_state
     }
 
-    fn event(&mut self, event: TEvent) -> Result<TState, TState, TEvent> {
+    fn deferred_events(&self) -> Vec<TEvent> {
+        self.deferred.iter().map(|(event, _)| *event).collect()
+    }
+
+    fn event_with(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
         if !self.is_started() {
             return Err(NotStarted);
         }
 
-        self.transition_on(event)
+        self.process(event, payload)
+    }
+
+    fn archived_history(&self) -> Vec<Vec<JournalEntry<TState, TEvent>>> {
+        self.journal.archived().to_vec()
     }
 
     fn has_cycles(&mut self) -> Option<bool> {
-        self.has_cycle.get_or_insert(crate::detect_cycles(&self.definition));
+        self.has_cycle.get_or_insert_with(|| crate::detect_cycles(&self.definition));
 
         self.has_cycle
     }
 
+    fn history(&self) -> Vec<JournalEntry<TState, TEvent>> {
+        self.journal.entries().copied().collect()
+    }
 
     fn is_end(&self) -> bool {
         self.definition.end_states.contains(&self.current_state)
@@ -84,53 +161,147 @@ impl<TState, TEvent> FiniteStateMachine<TState, TEvent> for StateMachine<TState,
         self.current_state != self.definition.initial_state
     }
 
+    fn new_fallible_triggers(
+        &mut self,
+        triggers: Vec<(TState, Vec<FallibleTrigger<TState, TEvent>>)>
+    ) -> Result<(), TState, TEvent> {
+        self.merge_triggers(triggers, Callback::Fallible)
+    }
+
     fn new_triggers(
         &mut self,
         triggers: Vec<(TState, Vec<Trigger<TState, TEvent>>)>
     ) -> Result<(), TState, TEvent> {
-        let undefined_states = triggers.iter()
-            .filter_map(|(state, _)|
-                if self.definition.transitions.contains_key(state) {
-                    None
-                } else {
-                    Some(*state)
-                }
-            )
-            .collect::<Vec<TState>>();
-
-        if !undefined_states.is_empty() {
-            return Err(UndefinedStates {
-                states: undefined_states
-            });
-        }
-
-        self.clear_triggers();
-
-        self.definition.triggers = Rc::new(triggers.into_iter().collect());
-
-        Ok(())
+        self.merge_triggers(triggers, Callback::Trigger)
     }
 
     fn next_states<'a>(&'a self) -> Box<dyn Iterator<Item=(&'a TEvent, &'a TState)> + 'a> {
         if self.is_end() {
             Box::new(empty())
         } else {
+            let guarded = self.definition.guards
+                .get(&self.current_state)
+                .into_iter()
+                .flatten()
+                .flat_map(|(event, candidates)| candidates.iter().map(move |(next, _)| (event, next)));
+
+            let previous = self.definition.previous
+                .get(&self.current_state)
+                .into_iter()
+                .flatten()
+                .filter_map(|event| self.previous_state.as_ref().map(|previous| (event, previous)));
+
             Box::new(self.definition.transitions
                 .get(&self.current_state)
                 .expect(ALL_STATES_WITH_TRANSITIONS)
-                .iter())
+                .iter()
+                .chain(guarded)
+                .chain(previous))
         }
     }
 
+    fn next_deadline(&self) -> Option<Instant> {
+        self.timeout().map(|(deadline, ..)| deadline)
+    }
+
+    fn previous_state(&self) -> Option<TState> {
+        self.previous_state
+    }
+
     fn reset(&mut self) -> TState {
         let last = self.current_state;
 
+        self.child = None;
         self.current_state = self.definition.initial_state;
+        self.deferred.clear();
+        self.entered = None;
+        self.journal.reset();
+        self.previous_state = None;
+        self.undo_stack.clear();
 
         last
     }
 
-    fn start(&mut self, event: TEvent) -> Result<TState, TState, TEvent> {
+    fn start_with(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        self.check_start(event)?;
+
+        self.process(event, payload)
+    }
+
+    fn tick(&mut self) -> Result<Option<TState>, TState, TEvent>
+        where TPayload: Default
+    {
+        match self.timeout() {
+            Some((deadline, event, depth)) if deadline <= self.clock.now() => {
+                let mut queue = EventQueue::new();
+
+                self.expire(depth, event, &TPayload::default(), &mut queue)?;
+                self.retry_deferred(&mut queue)?;
+                self.process_queue(&mut queue).map(Some)
+            }
+            _ => Ok(None)
+        }
+    }
+
+    fn undo(&mut self) -> Result<TState, TState, TEvent> {
+        if let Some(child) = &mut self.child {
+            if child.undo_stack.back().is_some_and(|step| step.from != child.definition.initial_state) {
+                return child.undo().map(|_| self.current_state);
+            }
+        }
+
+        let step = self.undo_stack.pop_back().ok_or(NothingToUndo)?;
+
+        self.definition.actions.compensate(step.event, step.to, step.from);
+
+        if step.from == self.definition.initial_state {
+            self.reset();
+            self.journal.record_undo(step.event, step.to, step.from);
+
+            return Ok(step.from);
+        }
+
+        self.journal.record_undo(step.event, step.to, step.from);
+
+        self.child = step.child;
+        self.current_state = step.from;
+        self.deferred.clear();
+        self.entered = Some(self.clock.now());
+        self.previous_state = step.previous_state;
+
+        Ok(step.from)
+    }
+}
+
+impl<TState, TEvent, TPayload> StateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash
+{
+    /// Resolves the next state of a transition on an event, without transitioning
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if the state machine has ended or the event does not
+    /// transition from the current state
+    ///
+    /// [`StateError`]: crate::state_machine::StateError
+    pub fn begin_transition(&self, event: TEvent) -> Result<TState, TState, TEvent> {
+        if self.is_end() {
+            return Err(EndState { end: self.current_state });
+        }
+
+        self.resolve(event)
+    }
+
+    /// Checks that the state machine can be started with an event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if the state machine is already started or the
+    /// event is not a start event
+    ///
+    /// [`StateError`]: crate::state_machine::StateError
+    pub fn check_start(&self, event: TEvent) -> Result<(), TState, TEvent> {
         if self.is_started() {
             return Err(AlreadyStarted {
                 current_state: self.current_state
@@ -142,34 +313,386 @@ impl<TState, TEvent> FiniteStateMachine<TState, TEvent> for StateMachine<TState,
             return Err(NotAStartEvent { event });
         }
 
-        self.transition_on(event)
+        Ok(())
     }
-}
 
-impl<TState, TEvent> StateMachine<TState, TEvent>
-    where TState: Copy + Eq + Hash,
-          TEvent: Copy + Eq + Hash
-{
-    fn transition_on(&mut self, event: TEvent) -> Result<TState, TState, TEvent> {
-        if self.is_end() {
-            return Err(EndState { end: self.current_state });
+    /// Commits the current state of a transition, recording the transition in the journal
+    /// and the undo history, with the replaced sub state machine of the prior state;
+    /// the prior state becomes the previous state, unless the transition re-enters the
+    /// current state
+    fn commit(&mut self, event: TEvent, transition: TState, child: Option<Box<Self>>) {
+        self.journal.record(event, self.current_state, transition);
+
+        if self.undo_depth > 0 {
+            if self.undo_stack.len() == self.undo_depth {
+                self.undo_stack.pop_front();
+            }
+
+            self.undo_stack.push_back(Step {
+                child,
+                event,
+                from: self.current_state,
+                previous_state: self.previous_state,
+                to: transition,
+            });
+        }
+
+        if transition != self.current_state {
+            self.previous_state = Some(self.current_state);
+        }
+
+        self.current_state = transition;
+        self.entered = Some(self.clock.now());
+    }
+
+    /// Completes a transition once all of its triggers succeed, invoking the actions
+    /// of the transition and committing the current state, with the started sub state
+    /// machine of the current state, if any
+    pub(crate) fn complete_transition(&mut self, event: TEvent, transition: TState, child: Option<Box<Self>>) {
+        let replaced = std::mem::replace(&mut self.child, child);
+
+        self.definition.actions.invoke(event, self.current_state, transition);
+        self.commit(event, transition, replaced);
+    }
+
+    /// Indicates if the current state, or the current state of an active sub state
+    /// machine, defers an event
+    pub(crate) fn defers(&self, event: TEvent) -> bool {
+        self.definition.deferred.get(&self.current_state).is_some_and(|events| events.contains(&event)) ||
+            self.child.as_ref().is_some_and(|child| child.defers(event))
+    }
+
+    /// Transitions on an event, holding the event if it is deferred by the current
+    /// state, and retrying the held events after a successful transition
+    ///
+    /// # Results
+    ///
+    /// Returns the current state of the state machine
+    fn dispatch(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        match self.transition_on(event, &payload, queue) {
+            Ok(_) => self.retry_deferred(queue)?,
+            Err(GuardRejected { .. } | InvalidTransition { .. }) if self.defers(event) =>
+                self.deferred.push((event, payload)),
+            Err(error) => return Err(error),
+        }
+
+        Ok(self.current_state)
+    }
+
+    /// Enables the triggers of a state machine created without triggers, and of its
+    /// sub state machines, including the sub state machines of its undo history
+    pub(crate) fn enable_triggers(&mut self) {
+        self.triggers = true;
+
+        let children = self.child.iter_mut()
+            .chain(self.undo_stack.iter_mut().filter_map(|step| step.child.as_mut()));
+
+        for child in children {
+            child.enable_triggers();
+        }
+    }
+
+    /// Starts the sub state machine of a state, if the state is a composite state
+    fn enter(
+        &self,
+        state: TState,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<Option<Box<Self>>, TState, TEvent> {
+        self.sub_machine(state)
+            .map(|(start, mut child)| child.transition_on(start, payload, queue).map(|_| child))
+            .transpose()
+    }
+
+    /// Fires a timeout on the state machine that owns it, at a depth of the active sub
+    /// state machines, so that a sub state machine which handles the same event does
+    /// not consume the timeout of its parent
+    fn expire(
+        &mut self,
+        depth: usize,
+        event: TEvent,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        match &mut self.child {
+            Some(child) if depth > 0 =>
+                child.expire(depth - 1, event, payload, queue).map(|_| self.current_state),
+            _ => self.transition_own(event, payload, queue),
+        }
+    }
+
+    /// Indicates if an active sub state machine handled an event, the events a sub state
+    /// machine can not handle fall through to the transitions of this state machine
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`StateError`] of the sub state machine, other than an event it can
+    /// not transition on
+    ///
+    /// [`StateError`]: crate::state_machine::StateError
+    pub(crate) fn handled(transition: Result<TState, TState, TEvent>) -> Result<bool, TState, TEvent> {
+        match transition {
+            Ok(_) => Ok(true),
+            Err(EndState { .. } | GuardRejected { .. } | InvalidTransition { .. }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Transitions on an event, then on the follow-up events posted by queued
+    /// triggers, in order, until the event queue is empty
+    ///
+    /// _a failed follow-up event discards the remaining follow-up events_
+    pub(crate) fn process(&mut self, event: TEvent, payload: TPayload) -> Result<TState, TState, TEvent> {
+        let mut queue = EventQueue::new();
+
+        self.dispatch(event, payload, &mut queue)?;
+        self.process_queue(&mut queue)
+    }
+
+    /// Transitions on the follow-up events posted by queued triggers, in order,
+    /// until the event queue is empty
+    ///
+    /// # Results
+    ///
+    /// Returns the current state of the state machine
+    fn process_queue(&mut self, queue: &mut EventQueue<TEvent, TPayload>) -> Result<TState, TState, TEvent> {
+        for depth in 0.. {
+            let Some((event, payload)) = queue.next() else { break };
+
+            if depth == self.max_depth {
+                return Err(QueueOverflow { event, max_depth: self.max_depth });
+            }
+
+            self.dispatch(event, payload, queue)?;
+        }
+
+        Ok(self.current_state)
+    }
+
+    /// Merges triggers into the triggers of this instance of the state machine,
+    /// wrapping each trigger as a [`Callback`]
+    ///
+    /// _the first triggers of an instance replace the triggers of the state machine
+    /// definition, subsequent triggers are added to the triggers of their states_
+    fn merge_triggers<TTrigger>(
+        &mut self,
+        triggers: Vec<(TState, Vec<TTrigger>)>,
+        callback: impl Fn(TTrigger) -> Callback<TState, TEvent, TPayload>,
+    ) -> Result<(), TState, TEvent> {
+        let undefined_states = triggers.iter()
+            .filter_map(|(state, _)|
+                if self.definition.transitions.contains_key(state) ||
+                    self.definition.end_states.contains(state) {
+                    None
+                } else {
+                    Some(*state)
+                }
+            )
+            .collect::<Vec<TState>>();
+
+        if !undefined_states.is_empty() {
+            return Err(UndefinedStates {
+                states: undefined_states
+            });
         }
 
-        let transitions = &self.definition
-            .transitions.get(&self.current_state)
-            .expect("all states should have defined transitions");
+        if !self.custom_triggers {
+            self.clear_triggers();
+        }
+
+        let existing = Shared::get_mut(&mut self.definition.triggers).expect(CUSTOM_TRIGGERS_NOT_SHARED);
 
-        let transition = *transitions.get(&event)
-            .ok_or(InvalidTransition { event, current_state: self.current_state })?;
+        for (state, triggers) in triggers {
+            existing.entry(state).or_default().extend(triggers.into_iter().map(&callback));
+        }
 
-        if let Some(triggers) = self.definition.triggers.get(&transition) {
+        Ok(())
+    }
+
+    /// Retries the held deferred events, in the order they were deferred, until
+    /// none of them can transition; held events that are no longer deferred by
+    /// the current state are discarded
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if a retried event fails, other than an event
+    /// the current state can not transition on
+    ///
+    /// [`StateError`]: crate::state_machine::StateError
+    fn retry_deferred(&mut self, queue: &mut EventQueue<TEvent, TPayload>) -> Result<(), TState, TEvent> {
+        let mut index = 0;
+
+        while index < self.deferred.len() {
+            let (event, payload) = self.deferred.remove(index);
+
+            match self.transition_on(event, &payload, queue) {
+                Ok(_) => index = 0,
+                Err(GuardRejected { .. } | InvalidTransition { .. }) if self.defers(event) => {
+                    self.deferred.insert(index, (event, payload));
+
+                    index += 1;
+                }
+                Err(EndState { .. } | GuardRejected { .. } | InvalidTransition { .. }) => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the sub state machine of a state, paired with its start event, if the
+    /// state is a composite state
+    ///
+    /// _* the sub state machine shares the clock, the undo depth and the enabled
+    /// triggers of this state machine_
+    pub(crate) fn sub_machine(&self, state: TState) -> Option<(TEvent, Box<Self>)> {
+        self.definition.sub_machines.get(&state)
+            .map(|(start, definition)| {
+                let child = Self::with_options(definition.clone(), StateMachineOptions {
+                    clock: self.clock.clone(),
+                    triggers: self.triggers,
+                    undo_depth: self.undo_depth,
+                    ..StateMachineOptions::new()
+                });
+
+                (*start, Box::new(child))
+            })
+    }
+
+    /// Gets the earliest timeout of the current state, or of the current states of
+    /// any active sub state machines
+    ///
+    /// _* a timeout of the current state takes precedence over a timeout of a sub
+    /// state machine with the same deadline_
+    ///
+    /// # Results
+    ///
+    /// Returns the deadline, the event and the depth of the state machine of the
+    /// earliest timeout, if any, _i.e. `0` for this state machine, `1` for its active
+    /// sub state machine_
+    fn timeout(&self) -> Option<(Instant, TEvent, usize)> {
+        let timeout = self.entered
+            .zip(self.definition.timeouts.get(&self.current_state))
+            .map(|(entered, (duration, event))| (entered + *duration, *event, 0));
+
+        let child = self.child.as_ref()
+            .and_then(|child| child.timeout())
+            .map(|(deadline, event, depth)| (deadline, event, depth + 1));
+
+        timeout.into_iter().chain(child).min_by_key(|(deadline, ..)| *deadline)
+    }
+
+    /// Transitions to the resolved next state of an event
+    ///
+    /// _the current state is only committed when all triggers succeed, a failed
+    /// trigger vetoes the transition and the remaining triggers, and the actions of
+    /// the transition, are not invoked_
+    ///
+    /// _an active sub state machine handles the event first, events it can not
+    /// handle fall through to the transitions of this state machine_
+    ///
+    /// _follow-up events posted by queued triggers, including the queued triggers
+    /// of sub state machines, are posted to the queue_
+    fn transition_on(
+        &mut self,
+        event: TEvent,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        if let Some(child) = &mut self.child {
+            if Self::handled(child.transition_on(event, payload, queue))? {
+                return Ok(self.current_state);
+            }
+        }
+
+        self.transition_own(event, payload, queue)
+    }
+
+    /// Transitions on the transitions of this state machine, without offering the
+    /// event to an active sub state machine first, _i.e. a timeout of this state machine_
+    fn transition_own(
+        &mut self,
+        event: TEvent,
+        payload: &TPayload,
+        queue: &mut EventQueue<TEvent, TPayload>,
+    ) -> Result<TState, TState, TEvent> {
+        let transition = self.begin_transition(event)?;
+
+        #[cfg(feature = "async")]
+        if self.definition.triggers.get(&transition).filter(|_| self.triggers)
+            .is_some_and(|triggers| triggers.iter().any(Callback::is_async)) {
+            return Err(AsyncTrigger { state: transition, event });
+        }
+
+        if let Some(triggers) = self.definition.triggers.get(&transition).filter(|_| self.triggers) {
             for trigger in triggers {
-                trigger(event, self.current_state, transition);
+                trigger.invoke(event, self.current_state, transition, payload, queue)
+                    .map_err(|source| TriggerFailed { state: transition, event, source })?;
             }
         }
 
-        self.current_state = transition;
+        let child = self.enter(transition, payload, queue)?;
+
+        self.complete_transition(event, transition, child);
 
         Ok(transition)
     }
+
+    /// Replays events, starting the state machine with the first event and
+    /// transitioning on each subsequent event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] with the index of the first event that fails
+    ///
+    /// [`StateError`]: crate::state_machine::StateError::ReplayFailed
+    pub fn replay(&mut self, events: impl IntoIterator<Item=TEvent>) -> Result<(), TState, TEvent>
+        where TPayload: Default
+    {
+        for (index, event) in events.into_iter().enumerate() {
+            let transition = if index == 0 { self.start(event) } else { self.event(event) };
+
+            transition.map_err(|source| ReplayFailed { index, source: Box::new(source) })?;
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the next state of a transition on an event from the current state
+    ///
+    /// _guarded transitions are evaluated in order, the first permitted transition
+    /// is used, otherwise the unguarded transition, or the transition to the previous
+    /// state, if any, is used_
+    fn resolve(&self, event: TEvent) -> Result<TState, TState, TEvent> {
+        let current_state = self.current_state;
+
+        let guarded = self.definition.guards
+            .get(&current_state)
+            .and_then(|guards| guards.get(&event));
+
+        if let Some((next, _)) = guarded.into_iter().flatten()
+            .find(|(_, guard)| guard(event, current_state)) {
+            return Ok(*next);
+        }
+
+        let transitions = self.definition
+            .transitions.get(&current_state)
+            .expect(ALL_STATES_WITH_TRANSITIONS);
+
+        let previous = self.definition.previous
+            .get(&current_state)
+            .is_some_and(|events| events.contains(&event));
+
+        match transitions.get(&event) {
+            Some(next) => Ok(*next),
+            None if previous => self.previous_state.ok_or(InvalidTransition { event, current_state }),
+            None if guarded.is_some() => Err(GuardRejected { event, current_state }),
+            None => Err(InvalidTransition { event, current_state })
+        }
+    }
 }
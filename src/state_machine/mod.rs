@@ -69,15 +69,52 @@
 //! ```
 //!
 //! [finite state machine]: https://en.wikipedia.org/wiki/Finite-state_machine
+pub(crate) use actions::Actions;
+pub(crate) use callback::Callback;
+pub(crate) use definition::{edges, Guards, SubMachines, Triggers};
+#[cfg(not(feature = "sync"))]
+pub(crate) use std::rc::Rc as Shared;
+#[cfg(feature = "sync")]
+pub(crate) use std::sync::Arc as Shared;
+#[cfg(feature = "async")]
+pub use async_fsm::AsyncFiniteStateMachine;
+pub use clock::{Clock, SystemClock};
+pub use coverage::{Coverage, Enumerable};
 pub use definition::StateMachineDefinition;
+pub use dense_definition::DenseStateMachineDefinition;
 pub use detect::detect_cycles;
 pub use error::StateError;
 pub use fsm::FiniteStateMachine;
+pub use journal::{JournalEntry, JournalOptions};
+pub use options::StateMachineOptions;
+pub use parallel_definition::ParallelStateMachineDefinition;
+pub use parallel_fsm::ParallelFiniteStateMachine;
+pub use queue::EventQueue;
 pub use result::Result;
+#[cfg(feature = "serde")]
+pub use snapshot::Snapshot;
 
+mod actions;
+#[cfg(feature = "async")]
+mod async_fsm;
+#[cfg(feature = "async")]
+mod async_machine;
+mod callback;
+mod clock;
+mod coverage;
 mod definition;
+mod dense_definition;
+mod dense_machine;
 mod detect;
 mod error;
 mod fsm;
+mod journal;
 mod machine;
+mod options;
+mod parallel_definition;
+mod parallel_fsm;
+mod parallel_machine;
+mod queue;
 mod result;
+#[cfg(feature = "serde")]
+mod snapshot;
@@ -0,0 +1,119 @@
+use crate::state_machine::{Clock, JournalOptions, Shared, SystemClock};
+
+const DEFAULT_MAX_DEPTH: usize = 64;
+const DEFAULT_UNDO_DEPTH: usize = 64;
+
+/// Options of a [`FiniteStateMachine`] created with [`create_with`]
+///
+/// _* the default options are the options of a state machine created with_ [`create`]
+///
+/// [`create`]: crate::StateMachineDefinition::create
+/// [`create_with`]: crate::StateMachineDefinition::create_with
+/// [`FiniteStateMachine`]: crate::FiniteStateMachine
+#[derive(Clone)]
+pub struct StateMachineOptions {
+    pub(crate) clock: Shared<dyn Clock>,
+    pub(crate) journal: JournalOptions,
+    pub(crate) max_depth: usize,
+    pub(crate) triggers: bool,
+    pub(crate) undo_depth: usize,
+}
+
+impl StateMachineOptions {
+    /// Initializes the default options of a state machine, _i.e. the_ [`SystemClock`],
+    /// _no journal, and a maximum depth of `64` for both the event queue and undo_
+    ///
+    /// # Results
+    ///
+    /// Returns the default options of a state machine
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            clock: Shared::new(SystemClock),
+            journal: JournalOptions::default(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            triggers: true,
+            undo_depth: DEFAULT_UNDO_DEPTH,
+        }
+    }
+
+    /// Sets the clock of the state machine, which times the states with timed
+    /// transitions, _i.e. a fake clock_
+    ///
+    /// # Arguments
+    ///
+    /// * _`clock`_ - the clock of the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the options with the clock of the state machine
+    #[must_use]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Shared::new(clock);
+
+        self
+    }
+
+    /// Sets the options of the bounded journal of the state machine, which records
+    /// its transitions, read with [`history`]
+    ///
+    /// # Arguments
+    ///
+    /// * _`journal`_ - the [`JournalOptions`] of the journal
+    ///
+    /// # Results
+    ///
+    /// Returns the options with the journal of the state machine
+    ///
+    /// [`history`]: crate::FiniteStateMachine::history
+    #[must_use]
+    pub const fn journal(mut self, journal: JournalOptions) -> Self {
+        self.journal = journal;
+
+        self
+    }
+
+    /// Sets the maximum depth of the event queue, _i.e. the maximum number of
+    /// follow-up events, posted by queued triggers, that are processed for an event_
+    ///
+    /// # Arguments
+    ///
+    /// * _`max_depth`_ - the maximum depth of the event queue
+    ///
+    /// # Results
+    ///
+    /// Returns the options with the maximum depth of the event queue
+    #[must_use]
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+
+        self
+    }
+
+    /// Sets the maximum number of transitions that can be undone, with [`undo`],
+    /// the oldest transitions are discarded once the limit is reached
+    ///
+    /// _* an undo depth of `0` disables undo_
+    ///
+    /// # Arguments
+    ///
+    /// * _`undo_depth`_ - the maximum number of transitions that can be undone
+    ///
+    /// # Results
+    ///
+    /// Returns the options with the maximum depth of the undo history
+    ///
+    /// [`undo`]: crate::FiniteStateMachine::undo
+    #[must_use]
+    pub const fn undo_depth(mut self, undo_depth: usize) -> Self {
+        self.undo_depth = undo_depth;
+
+        self
+    }
+}
+
+impl Default for StateMachineOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,69 @@
+use std::hash::Hash;
+
+use crate::state_machine::parallel_machine::ParallelStateMachine;
+use crate::state_machine::{ParallelFiniteStateMachine, StateMachineDefinition};
+
+/// Definition of a state machine with parallel regions, each region is an
+/// independent [`StateMachineDefinition`], with its own states and transition
+/// edges
+///
+/// An instance can be initialized with the [`StateMachineBuilder`], defining
+/// regions with the [`BuilderState::add_region`] method, and is instantiated
+/// with the [`BuilderState::build_parallel`] method.
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
+///
+/// [`StateMachineBuilder`]: crate::StateMachineBuilder
+/// [`BuilderState::add_region`]: crate::builder::BuilderState::add_region
+/// [`BuilderState::build_parallel`]: crate::builder::BuilderState::build_parallel
+pub struct ParallelStateMachineDefinition<TState, TEvent, TPayload = ()> {
+    pub(crate) regions: Vec<StateMachineDefinition<TState, TEvent, TPayload>>,
+}
+
+// derived `Clone` would needlessly require `TEvent` and `TPayload` to be `Clone`
+impl<TState, TEvent, TPayload> Clone for ParallelStateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy
+{
+    fn clone(&self) -> Self {
+        Self {
+            regions: self.regions.clone(),
+        }
+    }
+}
+
+impl<TState, TEvent, TPayload> ParallelStateMachineDefinition<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash
+{
+    /// Creates a new [`ParallelFiniteStateMachine`] from the state machine definition
+    ///
+    /// _* each region receives a clone of the payload of an event, since a region
+    /// can hold on to a deferred event_
+    ///
+    /// # Results
+    ///
+    /// Returns an implementation of a [`ParallelFiniteStateMachine`]
+    ///
+    #[must_use]
+    pub fn create(&self) -> impl ParallelFiniteStateMachine<TState, TEvent, TPayload>
+        where TPayload: Clone
+    {
+        ParallelStateMachine::new(self.clone())
+    }
+
+    /// Gets the definitions of the regions of the state machine, in the order
+    /// they were defined
+    ///
+    /// # Results
+    ///
+    /// Returns the [`StateMachineDefinition`] of each region
+    #[must_use]
+    pub fn regions(&self) -> &[StateMachineDefinition<TState, TEvent, TPayload>] {
+        &self.regions
+    }
+}
@@ -0,0 +1,156 @@
+use crate::state_machine;
+
+/// _`ParallelFiniteStateMachine<TState, TEvent>`_ is a trait representing the
+/// api of a [state machine] with parallel regions
+///
+/// Every event is delivered to all regions, each region transitions on its own
+/// transition edges, and regions that do not transition on the event remain in
+/// their current state
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
+///
+/// [state machine]: https://en.wikipedia.org/wiki/Finite-state_machine
+pub trait ParallelFiniteStateMachine<TState, TEvent, TPayload = ()> {
+    /// Gets the current state of each region of the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the current state of each region, in the order the regions
+    /// were defined
+    fn current_states(&self) -> Vec<TState>;
+
+    /// Transitions each region of the state machine from its current state
+    /// to the next state based on its defined event transition edge
+    ///
+    /// _* triggers receive the default payload_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event triggering the transition
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of each region after the transition event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if no region transitions on the event, or there
+    /// are any other errors transitioning on event
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn event(&mut self, event: TEvent) -> state_machine::Result<Vec<TState>, TState, TEvent>
+        where TPayload: Default
+    {
+        self.event_with(event, TPayload::default())
+    }
+
+    /// Transitions each region of the state machine from its current state
+    /// to the next state based on its defined event transition edge,
+    /// delivering a payload to the triggers of the transitions
+    ///
+    /// _* regions transition in the order they were defined, a failed trigger
+    /// stops the event from reaching the remaining regions_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event triggering the transition
+    /// * _`payload`_ - payload delivered to the triggers
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of each region after the transition event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if no region transitions on the event, or there
+    /// are any other errors transitioning on event
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn event_with(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+    ) -> state_machine::Result<Vec<TState>, TState, TEvent>;
+
+    /// Checks if every region of the state machine is at an end state
+    ///
+    /// # Results
+    ///
+    /// Returns `true` if all regions have ended, `false` otherwise
+    fn is_end(&self) -> bool;
+
+    /// Checks if the state machine has started
+    ///
+    /// # Results
+    ///
+    /// Returns `true` if the state machine has started, `false` otherwise
+    fn is_started(&self) -> bool;
+
+    /// Resets each region of the state machine to it's initial state
+    ///
+    /// # Results
+    ///
+    /// Returns the prior state of each region before it was reset
+    fn reset(&mut self) -> Vec<TState>;
+
+    /// Starts every region of the state machine
+    ///
+    /// _* triggers receive the default payload_
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event starting the state machine, which must be a start
+    ///   event of every region
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of each region
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors starting the state machine
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn start(&mut self, event: TEvent) -> state_machine::Result<Vec<TState>, TState, TEvent>
+        where TPayload: Default
+    {
+        self.start_with(event, TPayload::default())
+    }
+
+    /// Starts every region of the state machine, delivering a payload to the
+    /// triggers of the start states
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event starting the state machine, which must be a start
+    ///   event of every region
+    /// * _`payload`_ - payload delivered to the triggers
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of each region
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateError`] if there are any errors starting the state machine
+    ///
+    /// _* see_ [`StateError`] _for details on the possible errors that can occur_
+    ///
+    /// [`StateError`]: state_machine::StateError
+    fn start_with(
+        &mut self,
+        event: TEvent,
+        payload: TPayload,
+    ) -> state_machine::Result<Vec<TState>, TState, TEvent>;
+}
@@ -0,0 +1,89 @@
+use std::hash::Hash;
+
+use crate::state_machine::{ParallelFiniteStateMachine, ParallelStateMachineDefinition, Result};
+use crate::state_machine::fsm::FiniteStateMachine;
+use crate::state_machine::machine::StateMachine;
+use crate::state_machine::StateError::{EndState, GuardRejected, InvalidTransition, NotStarted};
+
+// StateMachineBuilder built implementation of a ParallelFiniteStateMachine trait object
+pub struct ParallelStateMachine<TState, TEvent, TPayload> {
+    regions: Vec<StateMachine<TState, TEvent, TPayload>>,
+}
+
+impl<TState, TEvent, TPayload> ParallelStateMachine<TState, TEvent, TPayload>
+    where TState: Copy
+{
+    /// Initializes a new instance of a parallel state machine
+    ///
+    /// # Arguments
+    ///
+    /// * _`definition`_ - an instance of parallel state machine definition
+    ///
+    /// # Results
+    ///
+    /// Returns a new initialized instance of a parallel state machine
+    pub fn new(definition: ParallelStateMachineDefinition<TState, TEvent, TPayload>) -> Self {
+        Self {
+            regions: definition.regions.into_iter().map(StateMachine::new).collect(),
+        }
+    }
+}
+
+impl<TState, TEvent, TPayload> ParallelFiniteStateMachine<TState, TEvent, TPayload>
+    for ParallelStateMachine<TState, TEvent, TPayload>
+    where TState: Copy + Eq + Hash,
+          TEvent: Copy + Eq + Hash,
+          TPayload: Clone
+{
+    fn current_states(&self) -> Vec<TState> {
+        self.regions.iter().map(FiniteStateMachine::current_state).collect()
+    }
+
+    fn event_with(&mut self, event: TEvent, payload: TPayload) -> Result<Vec<TState>, TState, TEvent> {
+        if !self.is_started() {
+            return Err(NotStarted);
+        }
+
+        let mut transitioned = false;
+        let mut unhandled = None;
+
+        for region in &mut self.regions {
+            match region.process(event, payload.clone()) {
+                Ok(_) => transitioned = true,
+                Err(error @ (EndState { .. } | GuardRejected { .. } | InvalidTransition { .. })) => {
+                    unhandled.get_or_insert(error);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        match unhandled {
+            Some(error) if !transitioned => Err(error),
+            _ => Ok(self.current_states())
+        }
+    }
+
+    fn is_end(&self) -> bool {
+        self.regions.iter().all(FiniteStateMachine::is_end)
+    }
+
+    fn is_started(&self) -> bool {
+        self.regions.iter().all(FiniteStateMachine::is_started)
+    }
+
+    fn reset(&mut self) -> Vec<TState> {
+        self.regions.iter_mut().map(FiniteStateMachine::reset).collect()
+    }
+
+    fn start_with(&mut self, event: TEvent, payload: TPayload) -> Result<Vec<TState>, TState, TEvent> {
+        for region in &self.regions {
+            region.check_start(event)?;
+        }
+
+        for region in &mut self.regions {
+            region.process(event, payload.clone())?;
+        }
+
+        Ok(self.current_states())
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::VecDeque;
+
+/// The queue of follow-up events posted by queued triggers, which is processed
+/// to completion before the event that posted them returns
+///
+/// ### Generic Data Types
+///
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`TPayload`_ - represents the payload delivered with events to triggers,
+///   _defaults to `()`_
+#[derive(Debug)]
+pub struct EventQueue<TEvent, TPayload = ()> {
+    events: VecDeque<(TEvent, TPayload)>,
+}
+
+impl<TEvent, TPayload> EventQueue<TEvent, TPayload> {
+    /// Initializes a new empty queue
+    pub(crate) const fn new() -> Self {
+        Self {
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Takes the next posted event, in the order the events were posted
+    pub(crate) fn next(&mut self) -> Option<(TEvent, TPayload)> {
+        self.events.pop_front()
+    }
+
+    /// Posts a follow-up event, triggers receive the default payload
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the follow-up event
+    pub fn post(&mut self, event: TEvent)
+        where TPayload: Default
+    {
+        self.post_with(event, TPayload::default());
+    }
+
+    /// Posts a follow-up event, delivering a payload to the triggers of the
+    /// transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the follow-up event
+    /// * _`payload`_ - payload delivered to the triggers
+    pub fn post_with(&mut self, event: TEvent, payload: TPayload) {
+        self.events.push_back((event, payload));
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state_machine::JournalEntry;
+
+/// A serializable snapshot of a running [`FiniteStateMachine`], used to
+/// [`restore`] the state machine, _i.e. after a process restart_
+///
+/// _* a snapshot at the initial state is a snapshot of a state machine that was
+/// not started_<br>
+/// _** the current and previous states, and the journal, are saved, the undo
+/// history, held deferred events and sub state machines are not, a restored
+/// state machine has nothing to_ [`undo`]
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+///
+/// [`FiniteStateMachine`]: crate::FiniteStateMachine
+/// [`restore`]: crate::StateMachineDefinition::restore
+/// [`undo`]: crate::FiniteStateMachine::undo
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct Snapshot<TState, TEvent> {
+    /// the current state of the state machine
+    pub current_state: TState,
+
+    /// the journal of the state machine, read with [`history`], _empty if the
+    /// state machine does not have a journal, snapshots without a history
+    /// deserialize to an empty history_
+    ///
+    /// [`history`]: crate::FiniteStateMachine::history
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<JournalEntry<TState, TEvent>>,
+
+    /// the previous state of the state machine, _snapshots without a previous
+    /// state deserialize to `None`_
+    pub previous_state: Option<TState>,
+}
@@ -0,0 +1,226 @@
+use crate::table::{TableStateMachine, TableTrigger};
+
+/// A state machine definition of fixed arrays indexed by state and event, which can be
+/// built in a `const` context and never allocates
+///
+/// _* states and events are indexed by their [`Indexed`] index, i.e. `State::A as usize`;
+/// defining a transition twice, an index out of bounds, or a table that does not pass
+/// [`validate`], panics, which is a compile error in a `const` context_
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`STATES`_ - the number of states, _including the initial state_
+/// * _`EVENTS`_ - the number of events
+///
+/// [`Indexed`]: crate::table::Indexed
+/// [`validate`]: StateTable::validate
+pub struct StateTable<TState, TEvent, const STATES: usize, const EVENTS: usize> {
+    pub(crate) end_states: [bool; STATES],
+    pub(crate) initial_state: TState,
+    pub(crate) initial_index: usize,
+    pub(crate) transitions: [[Option<(TEvent, TState, usize)>; EVENTS]; STATES],
+    pub(crate) triggers: [Option<TableTrigger<TState, TEvent>>; STATES],
+}
+
+impl<TState, TEvent, const STATES: usize, const EVENTS: usize> StateTable<TState, TEvent, STATES, EVENTS>
+    where TState: Copy,
+          TEvent: Copy
+{
+    /// Initializes a new state table without transitions
+    ///
+    /// # Arguments
+    ///
+    /// * _`initial_state`_ - the initial state of the state machine
+    /// * _`index`_ - the index of the initial state
+    ///
+    /// # Results
+    ///
+    /// Returns a new state table
+    #[must_use]
+    pub const fn new(initial_state: TState, index: usize) -> Self {
+        Self {
+            end_states: [false; STATES],
+            initial_state,
+            initial_index: index,
+            transitions: [[None; EVENTS]; STATES],
+            triggers: [None; STATES],
+        }
+    }
+
+    /// Creates a new state machine of the state table
+    ///
+    /// # Results
+    ///
+    /// Returns a new [`TableStateMachine`], at the initial state
+    #[must_use]
+    pub const fn create(&self) -> TableStateMachine<'_, TState, TEvent, STATES, EVENTS> {
+        TableStateMachine::new(self)
+    }
+
+    /// Defines an end state
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the index of the end state
+    ///
+    /// # Results
+    ///
+    /// Returns the state table
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end state is already defined, or if the index is out of bounds
+    #[must_use]
+    pub const fn end(mut self, state: usize) -> Self {
+        assert!(!self.end_states[state], "end state is already defined");
+
+        self.end_states[state] = true;
+        self
+    }
+
+    /// Defines a start transition, from the initial state
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - the start event
+    /// * _`index`_ - the index of the start event
+    /// * _`state`_ - the start state
+    /// * _`to`_ - the index of the start state
+    ///
+    /// # Results
+    ///
+    /// Returns the state table
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start transition is already defined, or if an index is out of bounds
+    #[must_use]
+    pub const fn start(self, event: TEvent, index: usize, state: TState, to: usize) -> Self {
+        let initial_index = self.initial_index;
+
+        self.transition(initial_index, event, index, state, to)
+    }
+
+    /// Defines a transition
+    ///
+    /// # Arguments
+    ///
+    /// * _`from`_ - the index of the state to transition from
+    /// * _`event`_ - the event to transition on
+    /// * _`index`_ - the index of the event
+    /// * _`state`_ - the state to transition to
+    /// * _`to`_ - the index of the state to transition to
+    ///
+    /// # Results
+    ///
+    /// Returns the state table
+    ///
+    /// # Panics
+    ///
+    /// Panics if the transition is already defined, or if an index is out of bounds
+    #[must_use]
+    pub const fn transition(mut self, from: usize, event: TEvent, index: usize, state: TState, to: usize) -> Self {
+        assert!(to < STATES, "state index is out of bounds");
+        assert!(self.transitions[from][index].is_none(), "transition is already defined");
+
+        self.transitions[from][index] = Some((event, state, to));
+        self
+    }
+
+    /// Defines the trigger of a state, which is invoked when the state is entered
+    ///
+    /// # Arguments
+    ///
+    /// * _`state`_ - the index of the state
+    /// * _`trigger`_ - the trigger, _invoked with the event, the prior state and the next state_
+    ///
+    /// # Results
+    ///
+    /// Returns the state table
+    ///
+    /// # Panics
+    ///
+    /// Panics if the trigger is already defined, or if the index is out of bounds
+    #[must_use]
+    pub const fn trigger(mut self, state: usize, trigger: TableTrigger<TState, TEvent>) -> Self {
+        assert!(self.triggers[state].is_none(), "trigger is already defined");
+
+        self.triggers[state] = Some(trigger);
+        self
+    }
+
+    /// Validates the state table, _the same as a state machine builder validates its
+    /// definition_; the [`state_table!`] macro validates the tables it generates
+    ///
+    /// * the initial state must have at least one start transition
+    /// * the initial state can not be re-entered, or be an end state
+    /// * there must be at least one end state, and end states can not transition
+    /// * every state must be reachable from the initial state, _i.e. `STATES` is the
+    ///   number of states of the table_
+    ///
+    /// # Results
+    ///
+    /// Returns the validated state table
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state table is not valid, which is a compile error in a `const` context
+    ///
+    /// [`state_table!`]: crate::state_table
+    #[must_use]
+    pub const fn validate(self) -> Self {
+        let mut reachable = [false; STATES];
+        let mut has_end_state = false;
+        let mut has_start = false;
+        let mut changed = true;
+
+        assert!(!self.end_states[self.initial_index], "initial state can not be an end state");
+
+        reachable[self.initial_index] = true;
+
+        while changed {
+            changed = false;
+
+            let mut from = 0;
+
+            while from < STATES {
+                let mut event = 0;
+
+                while event < EVENTS {
+                    if let Some((_, _, to)) = self.transitions[from][event] {
+                        assert!(to != self.initial_index, "initial state can not be re-entered");
+                        assert!(!self.end_states[from], "end state can not transition");
+
+                        has_start |= from == self.initial_index;
+
+                        if reachable[from] && !reachable[to] {
+                            reachable[to] = true;
+                            changed = true;
+                        }
+                    }
+
+                    event += 1;
+                }
+
+                from += 1;
+            }
+        }
+
+        assert!(has_start, "initial state must have a start transition");
+
+        let mut state = 0;
+
+        while state < STATES {
+            assert!(reachable[state], "state is unreachable from the initial state");
+
+            has_end_state |= self.end_states[state];
+            state += 1;
+        }
+
+        assert!(has_end_state, "state table must have at least one end state");
+
+        self
+    }
+}
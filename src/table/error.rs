@@ -0,0 +1,85 @@
+use core::fmt::{Display, Formatter};
+
+/// The error type for [`TableStateMachine`] operations; _[`try_start`]_, _and [`try_event`]_
+///
+/// [`TableStateMachine`]: crate::table::TableStateMachine
+/// [`try_start`]: crate::table::TableStateMachine::try_start
+/// [`try_event`]: crate::table::TableStateMachine::try_event
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TableError<TState, TEvent> {
+    /// Occurs when attempting to start a state machine which is already started
+    AlreadyStarted {
+        /// the current state of the machine
+        current_state: TState,
+    },
+
+    /// Occurs when an event is attempted on a state machine that has ended
+    EndState {
+        /// the current state of the machine
+        end: TState
+    },
+
+    /// Occurs when an undefined event, for the current state, is attempted
+    /// on a state machine
+    InvalidTransition {
+        /// the invalid event
+        event: TEvent,
+
+        /// the current state of the machine
+        current_state: TState,
+    },
+
+    /// Occurs when attempting to start a state machine with an invalid event
+    NotAStartEvent {
+        /// the invalid event
+        event: TEvent
+    },
+
+    /// Occurs when an event is attempted on a state machine that has not been started
+    NotStarted,
+}
+
+impl<TState, TEvent> Display for TableError<TState, TEvent>
+    where TState: Display,
+          TEvent: Display,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AlreadyStarted { current_state } =>
+                fmt.write_fmt(format_args!(
+                    "State machine is already started; current state {current_state}"
+                )),
+
+            Self::EndState { end } =>
+                fmt.write_fmt(format_args!("Reached end state {end}")),
+
+            Self::InvalidTransition { event, current_state } =>
+                fmt.write_fmt(format_args!("Can not transition from {current_state} on {event}")),
+
+            Self::NotAStartEvent { event } =>
+                fmt.write_fmt(format_args!("{event} is not a starting event")),
+
+            Self::NotStarted =>
+                fmt.write_fmt(format_args!("State machine is not started")),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<TState, TEvent> std::error::Error for TableError<TState, TEvent>
+    where TState: std::fmt::Debug + Display,
+          TEvent: std::fmt::Debug + Display,
+{}
+
+#[cfg(feature = "std")]
+impl<TState, TEvent> From<TableError<TState, TEvent>> for crate::state_machine::StateError<TState, TEvent> {
+    fn from(error: TableError<TState, TEvent>) -> Self {
+        match error {
+            TableError::AlreadyStarted { current_state } => Self::AlreadyStarted { current_state },
+            TableError::EndState { end } => Self::EndState { end },
+            TableError::InvalidTransition { event, current_state } => Self::InvalidTransition { event, current_state },
+            TableError::NotAStartEvent { event } => Self::NotAStartEvent { event },
+            TableError::NotStarted => Self::NotStarted,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{FallibleTrigger, FiniteStateMachine, state_machine, Trigger};
+use crate::state_machine::StateError::StaticTriggers;
+use crate::table::{Indexed, TableStateMachine};
+
+// a table state machine does not record a journal, keep an undo stack, or time its
+// states, it only looks up its transitions
+impl<TState, TEvent, const STATES: usize, const EVENTS: usize> FiniteStateMachine<TState, TEvent>
+for TableStateMachine<'_, TState, TEvent, STATES, EVENTS>
+    where TState: Copy + Indexed,
+          TEvent: Copy + Indexed
+{
+    fn clear_triggers(&mut self) {
+        self.triggers = false;
+    }
+
+    fn current_state(&self) -> TState {
+        self.current_state
+    }
+
+    fn event_with(&mut self, event: TEvent, _payload: ()) -> state_machine::Result<TState, TState, TEvent> {
+        self.try_event(event).map_err(Into::into)
+    }
+
+    fn is_end(&self) -> bool {
+        Self::is_end(self)
+    }
+
+    fn is_started(&self) -> bool {
+        self.started
+    }
+
+    fn new_fallible_triggers(
+        &mut self,
+        _triggers: Vec<(TState, Vec<FallibleTrigger<TState, TEvent>>)>,
+    ) -> state_machine::Result<(), TState, TEvent> {
+        Err(StaticTriggers)
+    }
+
+    fn new_triggers(
+        &mut self,
+        _triggers: Vec<(TState, Vec<Trigger<TState, TEvent>>)>,
+    ) -> state_machine::Result<(), TState, TEvent> {
+        Err(StaticTriggers)
+    }
+
+    fn next_states<'a>(&'a self) -> Box<dyn Iterator<Item=(&'a TEvent, &'a TState)> + 'a> {
+        Box::new(
+            self.table.transitions[self.current_state.index()].iter()
+                .flatten()
+                .map(|(event, state, _)| (event, state))
+        )
+    }
+
+    fn previous_state(&self) -> Option<TState> {
+        self.previous_state
+    }
+
+    fn reset(&mut self) -> TState {
+        Self::reset(self)
+    }
+
+    fn start_with(&mut self, event: TEvent, _payload: ()) -> state_machine::Result<TState, TState, TEvent> {
+        self.try_start(event).map_err(Into::into)
+    }
+}
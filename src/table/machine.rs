@@ -0,0 +1,200 @@
+use core::ops::Deref;
+
+use crate::table::{Indexed, Result, StateTable};
+use crate::table::TableError::{AlreadyStarted, EndState, InvalidTransition, NotAStartEvent, NotStarted};
+
+/// A state machine of a [`StateTable`], which looks up its transitions by index
+/// and never allocates
+///
+/// ### Generic Data Types
+///
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+/// * _`STATES`_ - the number of states, _including the initial state_
+/// * _`EVENTS`_ - the number of events
+///
+/// ### `Deref<Target=TState>`
+///
+/// `TableStateMachine` implements `Deref` with a target of type of `TState`,
+/// de-referencing to the current state
+pub struct TableStateMachine<'a, TState, TEvent, const STATES: usize, const EVENTS: usize> {
+    pub(crate) current_state: TState,
+    pub(crate) previous_state: Option<TState>,
+    pub(crate) started: bool,
+    pub(crate) table: &'a StateTable<TState, TEvent, STATES, EVENTS>,
+    pub(crate) triggers: bool,
+}
+
+impl<'a, TState, TEvent, const STATES: usize, const EVENTS: usize> TableStateMachine<'a, TState, TEvent, STATES, EVENTS>
+    where TState: Copy,
+          TEvent: Copy
+{
+    /// Initializes a new instance of a state machine, at the initial state
+    ///
+    /// # Arguments
+    ///
+    /// * _`table`_ - the state table of the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns a new initialized instance of a state machine
+    pub(crate) const fn new(table: &'a StateTable<TState, TEvent, STATES, EVENTS>) -> Self {
+        Self {
+            current_state: table.initial_state,
+            previous_state: None,
+            started: false,
+            table,
+            triggers: true,
+        }
+    }
+
+    /// Gets the current state of the state machine
+    #[must_use]
+    pub const fn current_state(&self) -> TState {
+        self.current_state
+    }
+
+    /// Checks if the state machine has started
+    ///
+    /// # Results
+    ///
+    /// Returns `true` if the state machine has started, `false` otherwise
+    #[must_use]
+    pub const fn is_started(&self) -> bool {
+        self.started
+    }
+
+    /// Gets the previous state of the state machine, _i.e. the state the state
+    /// machine was in before it entered the current state_
+    ///
+    /// # Results
+    ///
+    /// Returns the previous state, or `None` if the state machine has not transitioned
+    #[must_use]
+    pub const fn previous_state(&self) -> Option<TState> {
+        self.previous_state
+    }
+
+    /// Resets the state machine to it's initial state
+    ///
+    /// # Results
+    ///
+    /// Returns the prior state of the state machine before it was reset
+    pub const fn reset(&mut self) -> TState {
+        let prior = self.current_state;
+
+        self.current_state = self.table.initial_state;
+        self.previous_state = None;
+        self.started = false;
+
+        prior
+    }
+}
+
+impl<TState, TEvent, const STATES: usize, const EVENTS: usize> TableStateMachine<'_, TState, TEvent, STATES, EVENTS>
+    where TState: Copy + Indexed,
+          TEvent: Copy + Indexed
+{
+    /// Checks if the current state of the state machine is an end state
+    ///
+    /// # Results
+    ///
+    /// Returns `true` if the state machine has ended, `false` otherwise
+    #[must_use]
+    pub fn is_end(&self) -> bool {
+        self.started && self.table.end_states[self.current_state.index()]
+    }
+
+    /// Transitions the state machine from the current state to the next
+    /// state based on the defined event transition edge
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event triggering the transition
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine after the transition event
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TableError`] if the state machine is not started, has ended, or
+    /// the event does not transition from the current state
+    ///
+    /// [`TableError`]: crate::table::TableError
+    pub fn try_event(&mut self, event: TEvent) -> Result<TState, TState, TEvent> {
+        if !self.started {
+            return Err(NotStarted);
+        }
+
+        if self.is_end() {
+            return Err(EndState { end: self.current_state });
+        }
+
+        let current_state = self.current_state;
+
+        self.transition_on(event)
+            .ok_or(InvalidTransition { event, current_state })
+    }
+
+    /// Starts the state machine
+    ///
+    /// # Arguments
+    ///
+    /// * _`event`_ - event starting the state machine
+    ///
+    /// # Results
+    ///
+    /// Returns the new state of the state machine
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TableError`] if the state machine is already started, or the
+    /// event is not a start event
+    ///
+    /// [`TableError`]: crate::table::TableError
+    pub fn try_start(&mut self, event: TEvent) -> Result<TState, TState, TEvent> {
+        if self.started {
+            return Err(AlreadyStarted { current_state: self.current_state });
+        }
+
+        let state = self.transition_on(event).ok_or(NotAStartEvent { event })?;
+
+        self.started = true;
+
+        Ok(state)
+    }
+
+    /// Takes the transition of the current state on an event, if it is defined,
+    /// and invokes the trigger of the next state
+    fn transition_on(&mut self, event: TEvent) -> Option<TState> {
+        let current = self.current_state.index();
+        let (event, next, index) = self.table.transitions
+            .get(current)
+            .and_then(|transitions| transitions.get(event.index()))
+            .copied()
+            .flatten()?;
+
+        if self.triggers {
+            if let Some(trigger) = self.table.triggers[index] {
+                trigger(event, self.current_state, next);
+            }
+        }
+
+        if index != current {
+            self.previous_state = Some(self.current_state);
+        }
+
+        self.current_state = next;
+
+        Some(next)
+    }
+}
+
+impl<TState, TEvent, const STATES: usize, const EVENTS: usize> Deref for TableStateMachine<'_, TState, TEvent, STATES, EVENTS> {
+    type Target = TState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.current_state
+    }
+}
@@ -0,0 +1,133 @@
+//! Compile time state machine tables, _available without the `std` feature_
+//!
+//! A [`StateTable`] is a state machine definition of fixed arrays indexed by state and
+//! event, which can be built in a `const` context, or generated by the [`state_table!`]
+//! macro. Its [`TableStateMachine`] looks up transitions by index, invokes function
+//! pointer triggers and never allocates, so it can run on embedded `no_std` targets.
+//!
+//! ```rust
+//! use stately::state_table;
+//! use stately::table::{Indexed, StateTable};
+//!
+//! use Event::{Coin, Off, On, Push};
+//! use State::{Initial, Locked, Stopped, Unlocked};
+//!
+//! // https://en.wikipedia.org/wiki/Finite-state_machine
+//! const TURNSTILE: StateTable<State, Event, 4, 4> = state_table! {
+//!     Initial;
+//!     [*] --On--> Locked;
+//!     Locked --Coin--> Unlocked;
+//!     Locked --Push--> Locked;
+//!     Locked --Off--> Stopped;
+//!     Unlocked --Coin--> Unlocked;
+//!     Unlocked --Push--> Locked;
+//!     Stopped --> [*];
+//!     Unlocked => unlocked;
+//! };
+//!
+//! fn unlocked(_event: Event, _prior: State, _next: State) {}
+//!
+//! let mut turnstile = TURNSTILE.create();
+//!
+//! assert_eq!(Ok(Locked), turnstile.try_start(On));
+//! assert_eq!(Ok(Unlocked), turnstile.try_event(Coin));
+//! assert_eq!(Ok(Locked), turnstile.try_event(Push));
+//! assert_eq!(Ok(Stopped), turnstile.try_event(Off));
+//!
+//! #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+//! enum State {
+//!     Initial, Locked, Unlocked, Stopped,
+//! }
+//!
+//! #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+//! enum Event {
+//!     On, Push, Coin, Off,
+//! }
+//!
+//! impl Indexed for State {
+//!     fn index(self) -> usize { self as usize }
+//! }
+//!
+//! impl Indexed for Event {
+//!     fn index(self) -> usize { self as usize }
+//! }
+//! ```
+//!
+//! _* with the `std` feature, a [`TableStateMachine`] is also a_ `FiniteStateMachine`
+//!
+//! [`state_table!`]: crate::state_table
+pub use definition::StateTable;
+pub use error::TableError;
+pub use machine::TableStateMachine;
+pub use result::Result;
+
+mod definition;
+mod error;
+#[cfg(feature = "std")]
+mod fsm;
+mod machine;
+mod result;
+
+/// A type that represents the function pointer trigger of a state of a [`StateTable`],
+/// which is invoked when the state is entered
+///
+///### Arguments
+///
+/// * _`TEvent`_ - the event that triggered the transition
+/// * _`TState`_ -  the prior state
+/// * _`TState`_ -  the next state
+pub type TableTrigger<TState, TEvent> = fn(TEvent, TState, TState);
+
+/// A state, or an event, of a [`StateTable`], which is indexed by its index,
+/// _i.e. the discriminant of a fieldless enum,_ `self as usize`
+///
+/// _* the index must be less than the number of states, or events, of the table_
+pub trait Indexed {
+    /// Gets the index of the state, or the event
+    ///
+    /// # Results
+    ///
+    /// Returns the index
+    fn index(self) -> usize;
+}
+
+/// Generates a [`StateTable`] with a [Mermaid] like state diagram, which can be
+/// evaluated in a `const` context
+///
+/// * `Initial;` - the initial state, _the first line_
+/// * `[*] --Event--> State;` - a start state, entered on the event from the initial state
+/// * `State --Event--> State;` - a transition of a state
+/// * `State --> [*];` - an end state
+/// * `State => trigger;` - the function pointer trigger of a state, invoked when it is entered
+///
+/// States and events are the imported variants of fieldless enums, which are indexed
+/// by their discriminants, _i.e._ `State::A as usize`; the generated table is validated,
+/// so a duplicate transition or end state, an unreachable state, or a table without
+/// start transitions or end states is a compile error in a `const` context
+///
+/// _* see_ [`StateTable::validate`] _for the rules of a valid table_
+///
+/// [Mermaid]: https://mermaid.js.org/syntax/stateDiagram.html
+#[macro_export]
+macro_rules! state_table {
+    ($initial:ident; $($lines:tt)*) => {
+        $crate::state_table!(@line $crate::table::StateTable::new($initial, $initial as usize); $($lines)*)
+    };
+    (@line $table:expr; [*] --$event:ident--> $state:ident; $($lines:tt)*) => {
+        $crate::state_table!(@line $table.start($event, $event as usize, $state, $state as usize); $($lines)*)
+    };
+    (@line $table:expr; $state:ident --> [*]; $($lines:tt)*) => {
+        $crate::state_table!(@line $table.end($state as usize); $($lines)*)
+    };
+    (@line $table:expr; $from:ident --$event:ident--> $state:ident; $($lines:tt)*) => {
+        $crate::state_table!(
+            @line $table.transition($from as usize, $event, $event as usize, $state, $state as usize); $($lines)*
+        )
+    };
+    (@line $table:expr; $state:ident => $trigger:path; $($lines:tt)*) => {
+        $crate::state_table!(@line $table.trigger($state as usize, $trigger); $($lines)*)
+    };
+    (@line $table:expr;) => {
+        $table.validate()
+    };
+}
@@ -0,0 +1,15 @@
+use crate::table::TableError;
+
+/// Result is a type that represents either success ([`Ok`]) or failure ([`Err`])
+/// for [`TableStateMachine`] operations
+///
+/// ### Generic Data Types
+///
+/// * _`T`_ - successful return type
+/// * _`TState`_ - represents the states of a state machine
+/// * _`TEvent`_ - represents the transition events of a state machine
+///
+/// [`Ok`]: Ok
+/// [`Err`]: Err
+/// [`TableStateMachine`]: crate::table::TableStateMachine
+pub type Result<T, TState, TEvent> = core::result::Result<T, TableError<TState, TEvent>>;
@@ -0,0 +1,226 @@
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use Event::{Next, Start, Stop};
+use State::{Initial, Loop, Started, Stopped};
+
+use crate::prelude::*;
+use crate::state_machine::StateError::{AsyncTrigger, NotStarted};
+
+type Invoked = Arc<Mutex<Vec<&'static str>>>;
+
+#[test]
+fn given_an_async_fsm_it_should_await_triggers_in_order() {
+    let (definition, invoked) = subject_under_test();
+    let mut sut = definition.create_async();
+
+    assert_eq!(Started, block_on(sut.start(Start)).unwrap());
+    assert_eq!(vec!["started first", "started second", "started sync"], *invoked.lock().unwrap());
+
+    invoked.lock().unwrap().clear();
+
+    assert_eq!(Loop, block_on(sut.event(Next)).unwrap());
+    assert_eq!(vec!["loop"], *invoked.lock().unwrap());
+
+    assert_eq!(Stopped, block_on(sut.event(Stop)).unwrap());
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_an_async_fsm_it_should_not_transition_without_starting() {
+    let (definition, invoked) = subject_under_test();
+    let mut sut = definition.create_async();
+
+    assert!(matches!(block_on(sut.event(Next)), Err(NotStarted)));
+    assert_eq!(Initial, *sut);
+    assert!(invoked.lock().unwrap().is_empty());
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn given_an_async_fsm_with_the_sync_feature_its_futures_should_be_send() {
+    fn spawn<TFuture>(future: TFuture) -> thread::JoinHandle<TFuture::Output>
+        where TFuture: Future + Send + 'static,
+              TFuture::Output: Send + 'static
+    {
+        thread::spawn(move || block_on(future))
+    }
+
+    let (definition, invoked) = subject_under_test();
+
+    let handle = spawn(async move {
+        let mut sut = definition.create_async();
+
+        sut.start(Start).await
+    });
+
+    assert_eq!(Started, handle.join().unwrap().unwrap());
+    assert_eq!(3, invoked.lock().unwrap().len());
+}
+
+#[test]
+fn given_an_async_fsm_with_deferred_events_it_should_retry_a_deferred_event_after_a_transition() {
+    let (definition, invoked) = subject_under_test();
+    let mut sut = definition.create_async();
+
+    block_on(sut.start(Start)).unwrap();
+
+    assert_eq!(Started, block_on(sut.event(Stop)).unwrap());
+    assert_eq!(Stopped, block_on(sut.event(Next)).unwrap());
+    assert_eq!(Some(&"loop"), invoked.lock().unwrap().last());
+}
+
+#[test]
+fn given_an_async_fsm_with_a_sub_machine_it_should_await_the_triggers_of_the_sub_machine() {
+    let invoked = Invoked::default();
+
+    let record = |label: &'static str| {
+        let invoked = invoked.clone();
+
+        move |_event, _previous, _next| {
+            let invoked = invoked.clone();
+
+            async move {
+                YieldNow::default().await;
+
+                invoked.lock().unwrap().push(label);
+            }
+        }
+    };
+
+    let child = StateMachineBuilder::new()
+        .add_start_state(Start, Loop).unwrap()
+            .only_async_trigger(record("child loop"))
+            .only_transition_on(Next, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .only_async_trigger(record("child stopped"))
+        .build().unwrap();
+
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .sub_machine(Start, child).unwrap()
+            .only_async_trigger(record("started"))
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create_async();
+
+    assert_eq!(Started, block_on(sut.start(Start)).unwrap());
+    assert_eq!(vec!["started", "child loop"], *invoked.lock().unwrap());
+
+    assert_eq!(Started, block_on(sut.event(Next)).unwrap());
+    assert_eq!(vec!["started", "child loop", "child stopped"], *invoked.lock().unwrap());
+
+    assert_eq!(Stopped, block_on(sut.event(Stop)).unwrap());
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_an_fsm_with_async_triggers_it_should_not_transition_synchronously() {
+    let (definition, invoked) = subject_under_test();
+    let mut sut = definition.create();
+
+    assert!(matches!(sut.start(Start), Err(AsyncTrigger { state: Started, event: Start })));
+    assert_eq!(Initial, *sut);
+    assert!(invoked.lock().unwrap().is_empty());
+}
+
+fn subject_under_test() -> (StateMachineDefinition<State, Event>, Invoked) {
+    let invoked = Invoked::default();
+
+    let record = |invoked: &Invoked, label: &'static str| {
+        let invoked = invoked.clone();
+
+        move |_event, _previous, _next| {
+            let invoked = invoked.clone();
+
+            async move {
+                YieldNow::default().await;
+
+                invoked.lock().unwrap().push(label);
+            }
+        }
+    };
+
+    let definition = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .defer(Stop)
+            .async_trigger(record(&invoked, "started first"))
+            .async_trigger(record(&invoked, "started second"))
+            .final_trigger({
+                let invoked = invoked.clone();
+
+                move |_event, _previous, _next| invoked.lock().unwrap().push("started sync")
+            })
+            .only_transition_on(Next, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .only_async_trigger(record(&invoked, "loop"))
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap();
+
+    (definition, invoked)
+}
+
+/// A minimal executor, parking the current thread until the future is woken
+fn block_on<TFuture: Future>(future: TFuture) -> TFuture::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// A future that is pending once, yielding to the executor
+#[derive(Default)]
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+
+            context.waker().wake_by_ref();
+
+            Poll::Pending
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Next,
+    Start,
+    Stop,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Loop,
+    Started,
+    Stopped,
+}
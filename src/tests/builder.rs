@@ -1,13 +1,47 @@
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
 use Event::{Cycle, Next, Start, Stop};
 use State::{Initial, Loop, Started, Stopped};
 
 use crate::builder::BuilderError::{
-    RedefinedInitialState, StateAlreadyDefined, TransitionAlreadyDefined, ValidationError,
+    EndStateTransitions, NoPreviousState, NotAStartEvent, RedefinedInitialState, StateAlreadyDefined,
+    SubMachineAlreadyDefined, TimeoutAlreadyDefined, TransitionAlreadyDefined, TransitionToPreviousAlreadyDefined,
+    UndefinedTransition, ValidationError,
 };
 use crate::prelude::*;
 
+#[test]
+fn given_a_global_transition_to_an_undefined_state_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .add_global_transition(Cycle, Loop).unwrap()
+        .build();
+
+    assert!(matches!(
+        sut,
+        Err(ValidationError { undefined_states, .. }) if undefined_states == vec![Loop]
+    ));
+}
+
+#[test]
+fn given_a_group_transition_from_an_end_state_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .add_group_transition(&[Started, Stopped], Cycle, Started)
+        .build();
+
+    assert!(matches!(sut, Err(EndStateTransitions { state: Stopped })));
+}
+
 #[test]
 fn given_a_start_end_state_it_should_be_possible_to_define_multiple_triggers() {
     let sut = StateMachineBuilder::new()
@@ -116,6 +150,38 @@ fn given_a_start_state_it_should_be_possible_to_define_only_one_trigger() {
     assert!(matches!(sut, Ok(_)));
 }
 
+#[test]
+fn given_a_start_state_transitioning_to_the_previous_state_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_to_previous_on(Next).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build();
+
+    assert!(matches!(sut, Err(NoPreviousState { state: Started })));
+}
+
+#[test]
+fn given_a_state_it_should_be_possible_to_define_guarded_transitions() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on_if(Cycle, Loop, |_event, _state| true)
+            .final_transition_on_if(Cycle, Stopped, |_event, _state| true)
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .transition_on_if(Next, Started, |_event, _state| true)
+            .final_transition_on(Next, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build();
+
+    assert!(sut.is_ok());
+}
+
 #[test]
 fn given_a_state_it_should_be_possible_to_define_multiple_transitions() {
     let sut = StateMachineBuilder::new()
@@ -202,6 +268,33 @@ fn given_a_state_it_should_be_possible_to_define_only_one_trigger() {
     assert!(matches!(sut, Ok(_)));
 }
 
+#[test]
+fn given_a_sub_machine_with_an_incorrect_start_event_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .sub_machine(Next, sub_machine());
+
+    assert!(matches!(sut, Err(NotAStartEvent { event: Next })));
+}
+
+#[test]
+fn given_an_action_on_an_undefined_transition_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .on_transition(Cycle, unreachable_placeholder_trigger)
+            .no_triggers()
+            .only_transition_on(Next, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .build();
+
+    assert!(matches!(
+        sut,
+        Err(UndefinedTransition { state: Started, event: Cycle })
+    ));
+}
+
 #[test]
 fn given_an_end_state_it_should_be_possible_to_define_multiple_triggers() {
     let sut = StateMachineBuilder::new()
@@ -283,6 +376,27 @@ fn given_an_undefined_state_should_not_build_fsm() {
     ));
 }
 
+#[test]
+fn given_an_undefined_guarded_state_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on_if(Next, Loop, |_event, _state| true)
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build();
+
+    let expected_undefined = [Loop];
+
+    assert!(matches!(
+        sut,
+        Err(ValidationError { undefined_states, unreachable })
+            if undefined_states.iter().all(|itm| expected_undefined.contains(itm)) &&
+                unreachable.is_empty()
+    ));
+}
+
 #[test]
 fn given_an_unreachable_state_should_not_build_fsm() {
     let sut = StateMachineBuilder::new()
@@ -319,6 +433,41 @@ fn given_duplicate_states_should_not_build_fsm() {
     assert!(matches!(sut, Err(StateAlreadyDefined { state: Stopped })));
 }
 
+#[test]
+fn given_duplicate_global_transitions_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .add_global_transition(Cycle, Started).unwrap()
+        .add_global_transition(Cycle, Stopped);
+
+    assert!(matches!(sut, Err(TransitionAlreadyDefined { event: Cycle, existing: Started })));
+}
+
+#[test]
+fn given_duplicate_sub_machines_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .sub_machine(Start, sub_machine()).unwrap()
+            .sub_machine(Start, sub_machine());
+
+    assert!(matches!(sut, Err(SubMachineAlreadyDefined { state: Started })));
+}
+
+#[test]
+fn given_duplicate_timed_transitions_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_after(Duration::from_mins(1), Stop, Stopped).unwrap()
+            .final_transition_after(Duration::from_secs(30), Next, Stopped);
+
+    assert!(matches!(sut, Err(TimeoutAlreadyDefined { state: Started })));
+}
+
 #[test]
 fn given_duplicate_transitions_should_not_build_fsm() {
     let sut = StateMachineBuilder::new()
@@ -330,6 +479,20 @@ fn given_duplicate_transitions_should_not_build_fsm() {
     assert!(matches!(sut, Err(TransitionAlreadyDefined { event: Stop, existing: Stopped })));
 }
 
+#[test]
+fn given_duplicate_transitions_to_the_previous_state_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .transition_to_previous_on(Next).unwrap()
+            .final_transition_on(Next, Started);
+
+    assert!(matches!(sut, Err(TransitionToPreviousAlreadyDefined { event: Next })));
+}
+
 #[test]
 fn given_no_cycles_an_fsm_should_define_an_end_state() {
     let sut = StateMachineBuilder::new()
@@ -379,6 +542,16 @@ fn given_no_end_states_fsm_should_define_a_cycle() {
     assert!(sut.create().has_cycles().unwrap());
 }
 
+fn sub_machine() -> StateMachineDefinition<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+}
+
 fn unreachable_placeholder_trigger(_event: Event, _prior: State, _next: State) {
     unreachable!();
 }
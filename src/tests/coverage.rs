@@ -0,0 +1,166 @@
+use std::fmt::{Display, Formatter};
+
+use Event::{Cancel, Fail, Process, Retry, Schedule, Succeed};
+use State::{Canceled, Failed, Initial, Processing, Scheduled, Sent};
+
+use crate::builder::BuilderError::NotExhaustive;
+use crate::prelude::*;
+use crate::Enumerable;
+
+#[test]
+fn given_a_forgotten_state_should_not_build_strict_fsm() {
+    let sut = StateMachineBuilder::<State, Event>::new()
+        .add_start_state(Schedule, Scheduled).unwrap()
+            .no_triggers()
+            .transition_on(Cancel, Canceled).unwrap()
+            .final_transition_on(Process, Processing).unwrap()
+        .add_state(Processing).unwrap()
+            .no_triggers()
+            .transition_on(Retry, Scheduled).unwrap()
+            .final_transition_on(Succeed, Sent).unwrap()
+        .add_end_state(Canceled).unwrap()
+            .no_triggers()
+        .add_end_state(Sent).unwrap()
+            .no_triggers()
+        .build_strict();
+
+    assert!(matches!(
+        sut,
+        Err(NotExhaustive { missing_states, unused_events })
+            if missing_states == vec![Failed] && unused_events == vec![Fail]
+    ));
+}
+
+#[test]
+fn given_an_exhaustive_definition_should_build_strict_fsm() {
+    let mut sut = subject_under_test().build_strict().unwrap().create();
+
+    sut.start(Schedule).unwrap();
+
+    assert_eq!(Processing, sut.event(Process).unwrap());
+}
+
+#[test]
+fn given_a_definition_its_coverage_should_display_the_event_state_matrix() {
+    let sut = subject_under_test().build().unwrap().coverage();
+
+    let expected = concat!(
+        "           | Cancel   | Fail   | Process    | Retry     | Schedule  | Succeed\n",
+        "Initial    | -        | -      | -          | -         | Scheduled | -\n",
+        "Scheduled  | Canceled | -      | Processing | -         | -         | -\n",
+        "Processing | -        | Failed | -          | Scheduled | -         | Sent\n",
+        "Failed     | -        | -      | -          | Scheduled | -         | -\n",
+        "Canceled   | -        | -      | -          | -         | -         | -\n",
+        "Sent       | -        | -      | -          | -         | -         | -\n",
+    );
+
+    assert_eq!(expected, sut.to_string());
+}
+
+#[test]
+fn given_a_definition_its_coverage_should_list_the_next_states_of_each_state_and_event() {
+    let sut = StateMachineBuilder::<State, Event>::new()
+        .add_start_state(Schedule, Processing).unwrap()
+            .no_triggers()
+            .transition_on_if(Succeed, Sent, |_event, _state| true)
+            .final_transition_on(Succeed, Failed).unwrap()
+        .add_end_state(Sent).unwrap()
+            .no_triggers()
+        .add_end_state(Failed).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .coverage();
+
+    assert_eq!(&[Scheduled; 0], sut.next_states(Processing, Cancel));
+    assert_eq!(&[Failed, Sent], sut.next_states(Processing, Succeed));
+    assert_eq!(&[Scheduled, Canceled], sut.missing_states());
+    assert_eq!(&[Cancel, Fail, Process, Retry], sut.unused_events());
+    assert!(!sut.is_exhaustive());
+}
+
+#[test]
+fn given_a_sub_state_machine_its_states_and_events_should_be_covered() {
+    let child = StateMachineBuilder::new()
+        .add_start_state(Process, Processing).unwrap()
+            .no_triggers()
+            .transition_on(Fail, Failed).unwrap()
+            .final_transition_on(Succeed, Sent).unwrap()
+        .add_end_state(Failed).unwrap()
+            .no_triggers()
+        .add_end_state(Sent).unwrap()
+            .no_triggers()
+        .build().unwrap();
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Schedule, Scheduled).unwrap()
+            .sub_machine(Process, child).unwrap()
+            .no_triggers()
+            .transition_on(Retry, Scheduled).unwrap()
+            .final_transition_on(Cancel, Canceled).unwrap()
+        .add_end_state(Canceled).unwrap()
+            .no_triggers()
+        .build_strict();
+
+    assert!(sut.is_ok());
+}
+
+fn subject_under_test() -> impl BuilderState<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Schedule, Scheduled).unwrap()
+            .no_triggers()
+            .transition_on(Cancel, Canceled).unwrap()
+            .final_transition_on(Process, Processing).unwrap()
+        .add_state(Processing).unwrap()
+            .no_triggers()
+            .transition_on(Fail, Failed).unwrap()
+            .transition_on(Retry, Scheduled).unwrap()
+            .final_transition_on(Succeed, Sent).unwrap()
+        .add_state(Failed).unwrap()
+            .no_triggers()
+            .only_transition_on(Retry, Scheduled).unwrap()
+        .add_end_state(Canceled).unwrap()
+            .no_triggers()
+        .add_end_state(Sent).unwrap()
+            .no_triggers()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Cancel,
+    Fail,
+    Process,
+    Retry,
+    Schedule,
+    Succeed,
+}
+
+impl Display for Event {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+impl Enumerable for Event {
+    const VALUES: &'static [Self] = &[Cancel, Fail, Process, Retry, Schedule, Succeed];
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Scheduled,
+    Processing,
+    Failed,
+    Canceled,
+    Sent,
+}
+
+impl Display for State {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+impl Enumerable for State {
+    const VALUES: &'static [Self] = &[Initial, Scheduled, Processing, Failed, Canceled, Sent];
+}
@@ -0,0 +1,326 @@
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use Event::{Coin, Kick, On, Off, Push};
+use State::{Broken, Initial, Locked, Unlocked};
+
+use crate::builder::BuilderError::{DenseUnsupported, DuplicateEventIndex, ValidationError};
+use crate::prelude::*;
+use crate::state_machine::StateError::{
+    AlreadyStarted, EndState, InvalidTransition, NotAStartEvent, NotStarted, QueueOverflow, TriggerFailed,
+    UndefinedStates
+};
+use crate::table::Indexed;
+
+#[test]
+fn given_a_dense_fsm_events_should_transition() {
+    let mut sut = subject_under_test().create();
+
+    assert_eq!(Locked, sut.start(On).unwrap());
+    assert_eq!(Unlocked, sut.event(Coin).unwrap());
+    assert_eq!(Unlocked, sut.event(Coin).unwrap());
+    assert_eq!(Locked, sut.event(Push).unwrap());
+    assert_eq!(Locked, *sut);
+    assert_eq!(Some(Unlocked), sut.previous_state());
+}
+
+#[test]
+fn given_a_dense_fsm_an_end_state_should_not_transition() {
+    let mut sut = subject_under_test().create();
+
+    sut.start(On).unwrap();
+
+    assert_eq!(Broken, sut.event(Kick).unwrap());
+    assert!(sut.is_end());
+    assert!(matches!(sut.event(Coin), Err(EndState { end: Broken })));
+    assert_eq!(0, sut.next_states().count());
+}
+
+#[test]
+fn given_a_dense_fsm_invalid_events_should_not_transition() {
+    let mut sut = subject_under_test().create();
+
+    sut.start(On).unwrap();
+
+    assert!(matches!(sut.event(On), Err(InvalidTransition { event: On, current_state: Locked })));
+    // an event that no transition uses does not transition from any state
+    assert!(matches!(sut.event(Off), Err(InvalidTransition { event: Off, current_state: Locked })));
+    assert_eq!(Locked, sut.current_state());
+}
+
+#[test]
+fn given_a_dense_fsm_it_should_assign_the_initial_state_the_first_index() {
+    let sut = subject_under_test();
+
+    assert_eq!(Initial, sut.states()[0]);
+    assert_eq!(4, sut.states().len());
+    assert_eq!(4, sut.events().len());
+    assert!(!sut.events().contains(&Off));
+}
+
+#[test]
+fn given_a_dense_fsm_it_should_order_the_events_by_their_index() {
+    let sut = subject_under_test();
+
+    assert_eq!(&[Coin, Kick, On, Push], sut.events());
+}
+
+#[test]
+fn given_a_dense_fsm_it_should_list_the_next_states_of_the_current_state() {
+    let mut sut = subject_under_test().create();
+
+    sut.start(On).unwrap();
+
+    let mut next_states = sut.next_states().map(|(event, state)| (*event, *state)).collect::<Vec<_>>();
+
+    next_states.sort_by_key(|(event, _)| event.to_string());
+
+    assert_eq!(vec![(Coin, Unlocked), (Kick, Broken), (Push, Locked)], next_states);
+}
+
+#[test]
+fn given_a_dense_fsm_it_should_start_once_on_a_start_event() {
+    let mut sut = subject_under_test().create();
+
+    assert!(matches!(sut.event(Coin), Err(NotStarted)));
+    assert!(matches!(sut.start(Coin), Err(NotAStartEvent { event: Coin })));
+    assert!(matches!(sut.start(Off), Err(NotAStartEvent { event: Off })));
+
+    sut.start(On).unwrap();
+
+    assert!(sut.is_started());
+    assert!(matches!(sut.start(On), Err(AlreadyStarted { current_state: Locked })));
+}
+
+#[test]
+fn given_a_dense_fsm_resetting_it_should_return_to_the_initial_state() {
+    let mut sut = subject_under_test().create();
+
+    sut.start(On).unwrap();
+    sut.event(Coin).unwrap();
+
+    assert_eq!(Unlocked, sut.reset());
+    assert_eq!(Initial, sut.current_state());
+    assert_eq!(None, sut.previous_state());
+    assert!(!sut.is_started());
+}
+
+#[test]
+fn given_a_dense_fsm_with_custom_triggers_of_an_undefined_state_should_fail() {
+    let mut sut = subject_under_test().create();
+
+    let err = sut.new_triggers(vec![(Unlocked, vec![]), (State::Unused, vec![])]);
+
+    assert!(matches!(err, Err(UndefinedStates { states }) if states == vec![State::Unused]));
+}
+
+#[test]
+fn given_a_dense_fsm_with_fallible_triggers_a_failed_trigger_should_veto_transition() {
+    let mut sut = subject_under_test().create();
+
+    sut.new_fallible_triggers(vec![(Unlocked, vec![Box::new(|_event, _prior, _next| Err("jammed".into()))])])
+        .unwrap();
+    sut.start(On).unwrap();
+
+    assert!(matches!(sut.event(Coin), Err(TriggerFailed { state: Unlocked, event: Coin, .. })));
+    assert_eq!(Locked, sut.current_state());
+}
+
+#[test]
+fn given_a_dense_fsm_with_custom_and_fallible_triggers_both_should_trigger() {
+    let triggered = Arc::new(AtomicU32::new(0));
+    let mut sut = subject_under_test().create();
+
+    sut.new_triggers(vec![(Unlocked, vec![Box::new({
+        let triggered = triggered.clone();
+
+        move |_event, _prior, _next| { triggered.fetch_add(1, Ordering::Relaxed); }
+    })])]).unwrap();
+
+    sut.new_fallible_triggers(vec![(Broken, vec![Box::new(|_event, _prior, _next| Err("jammed".into()))])])
+        .unwrap();
+    sut.start(On).unwrap();
+
+    assert_eq!(Unlocked, sut.event(Coin).unwrap());
+    assert_eq!(1, triggered.load(Ordering::Relaxed));
+    assert_eq!(Locked, sut.event(Push).unwrap());
+    assert!(matches!(sut.event(Kick), Err(TriggerFailed { state: Broken, event: Kick, .. })));
+}
+
+#[test]
+fn given_a_dense_fsm_with_queued_triggers_events_should_process_follow_up_events() {
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(On, Locked).unwrap()
+            .no_triggers()
+            .only_transition_on(Coin, Unlocked).unwrap()
+        .add_state(Unlocked).unwrap()
+            .only_queued_trigger(|_event, _prior, _next, queue| queue.post(Push))
+            .only_transition_on(Push, Locked).unwrap()
+        .build_dense().unwrap()
+        .create();
+
+    sut.start(On).unwrap();
+
+    assert_eq!(Locked, sut.event(Coin).unwrap());
+    assert_eq!(Some(Unlocked), sut.previous_state());
+
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(On, Locked).unwrap()
+            .only_queued_trigger(|_event, _prior, _next, queue| queue.post(Coin))
+            .only_transition_on(Coin, Unlocked).unwrap()
+        .add_state(Unlocked).unwrap()
+            .only_queued_trigger(|_event, _prior, _next, queue| queue.post(Push))
+            .only_transition_on(Push, Locked).unwrap()
+        .build_dense().unwrap()
+        .create();
+
+    assert!(matches!(sut.start(On), Err(QueueOverflow { max_depth: 64, .. })));
+}
+
+#[test]
+fn given_a_dense_fsm_with_triggers_events_should_trigger_on_transition() {
+    let triggered = Arc::new(AtomicU32::new(0));
+    let counter = triggered.clone();
+
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(On, Locked).unwrap()
+            .no_triggers()
+            .only_transition_on(Coin, Unlocked).unwrap()
+        .add_end_state(Unlocked).unwrap()
+            .only_trigger(move |event, prior, next| {
+                assert_eq!((Coin, Locked, Unlocked), (event, prior, next));
+
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+        .build_dense().unwrap()
+        .create();
+
+    sut.start(On).unwrap();
+    sut.event(Coin).unwrap();
+
+    assert_eq!(1, triggered.load(Ordering::Relaxed));
+
+    sut.reset();
+    sut.clear_triggers();
+    sut.start(On).unwrap();
+    sut.event(Coin).unwrap();
+
+    assert_eq!(1, triggered.load(Ordering::Relaxed));
+}
+
+#[test]
+fn given_a_guarded_transition_should_not_build_dense_fsm() {
+    let sut = StateMachineBuilder::<State, Event>::new()
+        .add_start_state(On, Locked).unwrap()
+            .no_triggers()
+            .transition_on_if(Coin, Unlocked, |_event, _state| true)
+            .final_transition_on(Push, Locked).unwrap()
+        .add_end_state(Unlocked).unwrap()
+            .no_triggers()
+        .build_dense();
+
+    assert!(matches!(sut, Err(DenseUnsupported { state: Locked })));
+}
+
+#[test]
+fn given_events_with_the_same_index_should_not_build_dense_fsm() {
+    let sut = StateMachineBuilder::<State, Colliding>::new()
+        .add_start_state(Colliding::Coin, Locked).unwrap()
+            .no_triggers()
+            .only_transition_on(Colliding::Push, Unlocked).unwrap()
+        .add_end_state(Unlocked).unwrap()
+            .no_triggers()
+        .build_dense();
+
+    assert!(matches!(sut, Err(DuplicateEventIndex { index: 0, .. })));
+}
+
+#[test]
+fn given_an_unreachable_state_should_not_build_dense_fsm() {
+    let sut = StateMachineBuilder::<State, Event>::new()
+        .add_start_end_state(On, Locked).unwrap()
+            .no_triggers()
+        .add_end_state(Unlocked).unwrap()
+            .no_triggers()
+        .build_dense();
+
+    assert!(matches!(
+        sut,
+        Err(ValidationError { undefined_states, unreachable })
+            if undefined_states.is_empty() && unreachable == vec![Unlocked]
+    ));
+}
+
+// https://en.wikipedia.org/wiki/Finite-state_machine
+fn subject_under_test() -> DenseStateMachineDefinition<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(On, Locked).unwrap()
+            .no_triggers()
+            .transition_on(Push, Locked).unwrap()
+            .transition_on(Kick, Broken).unwrap()
+            .final_transition_on(Coin, Unlocked).unwrap()
+        .add_state(Unlocked).unwrap()
+            .no_triggers()
+            .transition_on(Coin, Unlocked).unwrap()
+            .final_transition_on(Push, Locked).unwrap()
+        .add_end_state(Broken).unwrap()
+            .no_triggers()
+        .build_dense().unwrap()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Coin,
+    Kick,
+    Off,
+    On,
+    Push,
+}
+
+impl Indexed for Event {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+// events that are all indexed by the same index
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Colliding {
+    Coin,
+    Push,
+}
+
+impl Indexed for Colliding {
+    fn index(self) -> usize {
+        0
+    }
+}
+
+impl Display for Colliding {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Broken,
+    Locked,
+    Unlocked,
+    Unused,
+}
+
+impl Display for State {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
@@ -0,0 +1,80 @@
+use Event::{Cancel, Complete, Process, Retry, Schedule, Skip};
+use State::{Cancelled, Completed, Initial, Processing, Scheduled, Skipped};
+
+use crate::prelude::*;
+use crate::{Enumerable, StateMachine};
+
+#[test]
+fn given_a_derived_fsm_it_should_transition_through_the_annotated_states() {
+    let mut sut = State::state_machine().create();
+
+    assert_eq!(Initial, sut.current_state());
+    assert!(matches!(sut.start(Schedule), Ok(Scheduled)));
+    assert!(matches!(sut.event(Process), Ok(Processing)));
+    assert!(matches!(sut.event(Retry), Ok(Scheduled)));
+    assert!(matches!(sut.event(Process), Ok(Processing)));
+    assert!(matches!(sut.event(Complete), Ok(Completed)));
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_a_derived_fsm_it_should_qualify_events_with_the_event_type() {
+    let mut sut = State::state_machine().create();
+
+    assert!(matches!(sut.start(Schedule), Ok(Scheduled)));
+    assert!(matches!(sut.event(Cancel), Ok(Cancelled)));
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_a_derived_fsm_with_a_start_end_state_it_should_end_on_start() {
+    let mut sut = State::state_machine().create();
+
+    assert!(matches!(sut.start(Skip), Ok(Skipped)));
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_enumerable_states_and_events_a_derived_fsm_should_cover_them() {
+    let sut = State::state_machine().coverage();
+
+    assert_eq!(&[Initial, Scheduled, Processing, Cancelled, Completed, Skipped], State::VALUES);
+    assert_eq!(&[Processing], sut.next_states(Scheduled, Process));
+    assert!(sut.is_exhaustive());
+}
+
+#[cfg(not(feature = "nightly"))]
+#[test]
+fn given_invalid_derived_fsms_they_should_not_compile() {
+    trybuild::TestCases::new().compile_fail("src/tests/invalid_derive/*.rs");
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Enumerable)]
+enum Event {
+    Cancel,
+    Complete,
+    Process,
+    Retry,
+    Schedule,
+    Skip,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Enumerable, StateMachine)]
+#[state_machine(event = Event)]
+enum State {
+    #[default]
+    Initial,
+    #[start(on = Schedule)]
+    #[transition(Process => Processing, Event::Cancel => Cancelled)]
+    Scheduled,
+    #[transition(Complete => Completed)]
+    #[transition(Retry => Scheduled)]
+    Processing,
+    #[end]
+    Cancelled,
+    #[end]
+    Completed,
+    #[start(on = Skip)]
+    #[end]
+    Skipped,
+}
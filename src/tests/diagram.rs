@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+
+use Event::{Cycle, Next, Start, Stop};
+use State::{Initial, Loop, Started, Stopped};
+
+use crate::prelude::*;
+
+#[test]
+fn given_a_definition_it_should_render_a_dot_digraph() {
+    let sut = subject_under_test().to_dot();
+
+    assert_eq!(
+        "digraph {\n    \
+            \"[*]\" [shape = point];\n    \
+            \"loop\" [shape = circle];\n    \
+            \"started\" [shape = circle, style = bold];\n    \
+            \"stopped\" [shape = doublecircle];\n    \
+            \"[*]\" -> \"started\" [label = \"Start\"];\n    \
+            \"loop\" -> \"started\" [label = \"Next\", color = red];\n    \
+            \"started\" -> \"loop\" [label = \"Cycle\", color = red];\n    \
+            \"started\" -> \"stopped\" [label = \"Stop\"];\n\
+        }\n",
+        sut
+    );
+}
+
+#[test]
+fn given_a_definition_with_self_loops_it_should_only_render_cyclical_edges_red() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Started).unwrap()
+            .final_transition_on(Next, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .to_dot();
+
+    assert_eq!(
+        "digraph {\n    \
+            \"[*]\" [shape = point];\n    \
+            \"loop\" [shape = circle];\n    \
+            \"started\" [shape = circle, style = bold];\n    \
+            \"stopped\" [shape = doublecircle];\n    \
+            \"[*]\" -> \"started\" [label = \"Start\"];\n    \
+            \"loop\" -> \"loop\" [label = \"Cycle\", color = red];\n    \
+            \"loop\" -> \"stopped\" [label = \"Stop\"];\n    \
+            \"started\" -> \"started\" [label = \"Cycle\", color = red];\n    \
+            \"started\" -> \"loop\" [label = \"Next\"];\n\
+        }\n",
+        sut
+    );
+}
+
+#[test]
+fn given_a_definition_with_a_start_end_state_it_should_render_a_dot_digraph() {
+    let sut = StateMachineBuilder::new()
+        .add_start_end_state(Stop, Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .to_dot_with(|state| format!("{state:?}"), |event| format!("{event:?}"));
+
+    assert_eq!(
+        "digraph {\n    \
+            \"[*]\" [shape = point];\n    \
+            \"Stopped\" [shape = doublecircle, style = bold];\n    \
+            \"[*]\" -> \"Stopped\" [label = \"Stop\"];\n\
+        }\n",
+        sut
+    );
+}
+
+#[test]
+fn given_a_definition_it_should_render_a_mermaid_diagram() {
+    let sut = subject_under_test().to_mermaid();
+
+    assert_eq!(
+        "stateDiagram-v2\n    \
+            [*] --> started : Start\n    \
+            loop --> started : Next\n    \
+            started --> loop : Cycle\n    \
+            started --> stopped : Stop\n    \
+            stopped --> [*]\n",
+        sut
+    );
+}
+
+#[test]
+fn given_a_definition_it_should_render_a_mermaid_diagram_with_custom_labels() {
+    let sut = subject_under_test()
+        .to_mermaid_with(|state| format!("{state:?}"), |event| format!("{event:?}"));
+
+    assert_eq!(
+        "stateDiagram-v2\n    \
+            [*] --> Started : Start\n    \
+            Loop --> Started : Next\n    \
+            Started --> Loop : Cycle\n    \
+            Started --> Stopped : Stop\n    \
+            Stopped --> [*]\n",
+        sut
+    );
+}
+
+#[test]
+fn given_a_definition_with_a_transition_to_the_previous_state_it_should_render_resolved_transitions() {
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Stop, Stopped).unwrap()
+            .final_transition_on(Cycle, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_to_previous_on(Next).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .to_mermaid();
+
+    assert_eq!(
+        "stateDiagram-v2\n    \
+            [*] --> started : Start\n    \
+            loop --> started : Next\n    \
+            started --> loop : Cycle\n    \
+            started --> stopped : Stop\n    \
+            stopped --> [*]\n",
+        sut
+    );
+}
+
+fn subject_under_test() -> StateMachineDefinition<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Stop, Stopped).unwrap()
+            .final_transition_on(Cycle, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Cycle,
+    Next,
+    Start,
+    Stop,
+}
+
+impl Display for Event {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Loop,
+    Started,
+    Stopped,
+}
+
+impl Display for State {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{}", match self {
+            Initial => "initial",
+            Loop => "loop",
+            Started => "started",
+            Stopped => "stopped",
+        }))
+    }
+}
@@ -0,0 +1,85 @@
+use Event::{Done, Loop, Next, Skip, Start};
+use State::{A, B, C, D, E, Initial};
+
+use crate::prelude::*;
+use crate::state_machine;
+
+#[test]
+fn given_a_dsl_fsm_it_should_transition_through_the_defined_states() {
+    let mut sut = subject_under_test().create();
+
+    assert_eq!(Initial, sut.current_state());
+    assert!(matches!(sut.start(Start), Ok(A)));
+    assert!(matches!(sut.event(Next), Ok(B)));
+    assert!(matches!(sut.event(Loop), Ok(A)));
+    assert!(matches!(sut.event(Next), Ok(B)));
+    assert!(matches!(sut.event(Next), Ok(C)));
+    assert!(matches!(sut.event(Done), Ok(D)));
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_a_dsl_fsm_it_should_have_cycles() {
+    let mut sut = subject_under_test().create();
+
+    assert!(matches!(sut.has_cycles(), Some(true)));
+}
+
+#[test]
+fn given_a_dsl_fsm_with_a_start_end_state_it_should_end_on_start() {
+    let mut sut = subject_under_test().create();
+
+    assert!(matches!(sut.start(Skip), Ok(E)));
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_a_dsl_fsm_redefining_the_initial_state_should_not_build_fsm() {
+    let sut = state_machine! {
+        [*] --Start--> A;
+        A --Next--> Initial;
+        Initial --> [*];
+    };
+
+    assert!(matches!(sut, Err(crate::builder::BuilderError::RedefinedInitialState)));
+}
+
+#[cfg(not(feature = "nightly"))]
+#[test]
+fn given_invalid_dsl_fsms_they_should_not_compile() {
+    trybuild::TestCases::new().compile_fail("src/tests/invalid_dsl/*.rs");
+}
+
+fn subject_under_test() -> StateMachineDefinition<State, Event> {
+    state_machine! {
+        [*] --Start--> A;
+        [*] --Skip--> E;
+        A --Next--> B;
+        B --Loop--> A;
+        B --Next--> C;
+        C --Event::Done--> D;
+        D --> [*];
+        E --> [*]
+    }
+        .expect("a valid state machine definition")
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Event {
+    Done,
+    Loop,
+    Next,
+    Skip,
+    Start,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+enum State {
+    #[default]
+    Initial,
+    A,
+    B,
+    C,
+    D,
+    E,
+}
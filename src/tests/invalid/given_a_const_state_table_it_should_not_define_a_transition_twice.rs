@@ -0,0 +1,30 @@
+use stately::state_table;
+use stately::table::StateTable;
+
+use Event::{Next, Start};
+use State::{A, B, Initial};
+
+const TABLE: StateTable<State, Event, 3, 2> = state_table! {
+    Initial;
+    [*] --Start--> A;
+    A --Next--> B;
+    A --Next--> A;
+    B --> [*];
+};
+
+fn main() {
+    let _fsm = TABLE.create();
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    Next,
+    Start,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum State {
+    Initial,
+    A,
+    B,
+}
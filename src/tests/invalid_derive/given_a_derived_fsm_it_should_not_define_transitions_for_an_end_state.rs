@@ -0,0 +1,24 @@
+use stately::StateMachine;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Complete,
+    Process,
+    Schedule,
+}
+
+fn main() {}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, StateMachine)]
+enum State {
+    #[default]
+    Initial,
+    #[start(on = Schedule)]
+    #[transition(Process => Processing)]
+    Scheduled,
+    #[transition(Complete => Completed)]
+    Processing,
+    #[end]
+    #[transition(Schedule => Scheduled)]
+    Completed,
+}
@@ -0,0 +1,23 @@
+use stately::StateMachine;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Complete,
+    Process,
+    Schedule,
+}
+
+fn main() {}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, StateMachine)]
+enum State {
+    #[default]
+    Initial,
+    #[start(on = Schedule)]
+    #[transition(Process => Processing, Process => Completed)]
+    Scheduled,
+    #[transition(Complete => Completed)]
+    Processing,
+    #[end]
+    Completed,
+}
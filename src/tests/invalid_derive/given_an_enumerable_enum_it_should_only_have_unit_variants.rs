@@ -0,0 +1,10 @@
+use stately::Enumerable;
+
+fn main() {}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Enumerable)]
+enum State {
+    Initial,
+    Retrying(u8),
+    Completed { at: u64 },
+}
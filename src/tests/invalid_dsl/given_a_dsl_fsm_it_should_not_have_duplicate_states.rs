@@ -0,0 +1,28 @@
+use stately::state_machine;
+
+use Event::{Next, Start};
+use State::{A, B, C};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Next,
+    Start,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    A,
+    B,
+    C,
+}
+
+fn main() {
+    let _fsm = state_machine! {
+        [*] --Start--> A;
+        A --Next--> B;
+        B --> [*];
+        B --> [*];
+    };
+}
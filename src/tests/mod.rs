@@ -1,3 +1,21 @@
+#[cfg(feature = "async")]
+mod async_state_machine;
 mod builder;
+mod coverage;
+mod dense;
+#[cfg(feature = "derive")]
+mod derive;
+mod diagram;
+#[cfg(feature = "dsl")]
+mod dsl;
 mod invalid;
+mod parallel;
+mod replay;
+#[cfg(feature = "serde")]
+mod schema;
+#[cfg(feature = "serde")]
+mod snapshot;
 mod state_machine;
+#[cfg(feature = "sync")]
+mod sync;
+mod table;
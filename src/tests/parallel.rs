@@ -0,0 +1,158 @@
+use std::fmt::{Display, Formatter};
+
+use Event::{Deliver, Pay, Place, Ship};
+use State::{Delivered, Initial, Invoiced, Packing, Paid, Shipped};
+
+use crate::builder::BuilderError::{ParallelRegions, ValidationError};
+use crate::prelude::*;
+use crate::state_machine::StateError::{InvalidTransition, NotAStartEvent, NotStarted};
+
+#[test]
+fn given_a_parallel_fsm_an_event_should_transition_each_region_that_handles_it() {
+    let mut sut = subject_under_test();
+
+    sut.start(Place).unwrap();
+
+    assert_eq!(vec![Shipped, Invoiced], sut.event(Ship).unwrap());
+    assert_eq!(vec![Shipped, Paid], sut.event(Pay).unwrap());
+}
+
+#[test]
+fn given_a_parallel_fsm_an_event_no_region_handles_should_not_transition() {
+    let mut sut = subject_under_test();
+
+    sut.start(Place).unwrap();
+
+    assert!(matches!(
+        sut.event(Deliver),
+        Err(InvalidTransition { event: Deliver, current_state: Packing })
+    ));
+    assert_eq!(vec![Packing, Invoiced], sut.current_states());
+}
+
+#[test]
+fn given_a_parallel_fsm_it_should_end_once_every_region_ends() {
+    let mut sut = subject_under_test();
+
+    sut.start(Place).unwrap();
+    sut.event(Pay).unwrap();
+
+    assert!(!sut.is_end());
+
+    sut.event(Ship).unwrap();
+    sut.event(Deliver).unwrap();
+
+    assert!(sut.is_end());
+    assert_eq!(vec![Delivered, Paid], sut.current_states());
+}
+
+#[test]
+fn given_a_parallel_fsm_it_should_start_every_region() {
+    let mut sut = subject_under_test();
+
+    assert!(matches!(sut.event(Ship), Err(NotStarted)));
+    assert!(matches!(sut.start(Ship), Err(NotAStartEvent { event: Ship })));
+    assert_eq!(vec![Initial, Initial], sut.current_states());
+
+    assert_eq!(vec![Packing, Invoiced], sut.start(Place).unwrap());
+    assert!(sut.is_started());
+}
+
+#[test]
+fn given_a_parallel_fsm_resetting_it_should_reset_every_region() {
+    let mut sut = subject_under_test();
+
+    sut.start(Place).unwrap();
+    sut.event(Pay).unwrap();
+
+    assert_eq!(vec![Packing, Paid], sut.reset());
+    assert_eq!(vec![Initial, Initial], sut.current_states());
+    assert!(!sut.is_started());
+}
+
+#[test]
+fn given_an_unreachable_state_in_a_region_should_not_build_parallel_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_end_state(Place, Paid).unwrap()
+            .no_triggers()
+        .add_region().unwrap()
+        .add_start_state(Place, Packing).unwrap()
+            .no_triggers()
+            .only_transition_on(Deliver, Delivered).unwrap()
+        .add_end_state(Delivered).unwrap()
+            .no_triggers()
+        .add_state(Shipped).unwrap()
+            .no_triggers()
+            .only_transition_on(Deliver, Delivered).unwrap()
+        .build_parallel();
+
+    assert!(matches!(
+        sut,
+        Err(ValidationError { undefined_states, unreachable })
+            if undefined_states.is_empty() && unreachable == vec![Shipped]
+    ));
+}
+
+#[test]
+fn given_parallel_regions_should_not_build_fsm() {
+    let sut = StateMachineBuilder::new()
+        .add_start_end_state(Place, Delivered).unwrap()
+            .no_triggers()
+        .add_region().unwrap()
+        .add_start_end_state(Place, Paid).unwrap()
+            .no_triggers()
+        .build();
+
+    assert!(matches!(sut, Err(ParallelRegions)));
+}
+
+fn subject_under_test() -> impl ParallelFiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Place, Packing).unwrap()
+            .no_triggers()
+            .only_transition_on(Ship, Shipped).unwrap()
+        .add_state(Shipped).unwrap()
+            .no_triggers()
+            .only_transition_on(Deliver, Delivered).unwrap()
+        .add_end_state(Delivered).unwrap()
+            .no_triggers()
+        .add_region().unwrap()
+        .add_start_state(Place, Invoiced).unwrap()
+            .no_triggers()
+            .only_transition_on(Pay, Paid).unwrap()
+        .add_end_state(Paid).unwrap()
+            .no_triggers()
+        .build_parallel().unwrap()
+        .create()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Deliver,
+    Pay,
+    Place,
+    Ship,
+}
+
+impl Display for Event {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Delivered,
+    Invoiced,
+    Packing,
+    Paid,
+    Shipped,
+}
+
+impl Display for State {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        fmt.write_fmt(format_args!("{self:?}"))
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use Event::{Cycle, Next, Start, Stop};
+use State::{Loop, Started, Stopped};
+
+use crate::prelude::*;
+use crate::state_machine::StateError::{InvalidTransition, NotAStartEvent, ReplayFailed};
+
+#[test]
+fn given_an_empty_event_log_replay_should_not_start_fsm() {
+    let (definition, _triggered) = subject_under_test();
+
+    let sut = definition.replay([]).unwrap();
+
+    assert!(!sut.is_started());
+}
+
+#[test]
+fn given_an_event_log_replay_should_rebuild_fsm() {
+    let (definition, triggered) = subject_under_test();
+
+    let sut = definition.replay([Start, Cycle, Next, Stop]).unwrap();
+
+    assert_eq!(Stopped, *sut);
+    assert!(sut.is_end());
+    assert_eq!(4, triggered.load(Ordering::SeqCst));
+}
+
+#[test]
+fn given_an_event_log_replay_without_triggers_should_not_trigger() {
+    let (definition, triggered) = subject_under_test();
+
+    let mut sut = definition.replay_without_triggers([Start, Cycle]).unwrap();
+
+    assert_eq!(Loop, *sut);
+    assert_eq!(0, triggered.load(Ordering::SeqCst));
+
+    sut.event(Next).unwrap();
+
+    assert_eq!(1, triggered.load(Ordering::SeqCst));
+}
+
+#[test]
+fn given_an_event_log_replay_without_triggers_should_not_trigger_sub_machines() {
+    let triggered = Arc::new(AtomicUsize::new(0));
+
+    let trigger = || {
+        let triggered = triggered.clone();
+
+        move |_event, _previous, _next| { triggered.fetch_add(1, Ordering::SeqCst); }
+    };
+
+    let child = StateMachineBuilder::new()
+        .add_start_state(Start, Loop).unwrap()
+            .only_trigger(trigger())
+            .only_transition_on(Next, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .only_trigger(trigger())
+        .build().unwrap();
+
+    let definition = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .sub_machine(Start, child).unwrap()
+            .only_trigger(trigger())
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap();
+
+    let mut sut = definition.replay_without_triggers([Start]).unwrap();
+
+    assert_eq!(vec![Started, Loop], sut.current_path());
+    assert_eq!(0, triggered.load(Ordering::SeqCst));
+
+    sut.event(Next).unwrap();
+
+    assert_eq!(vec![Started, Stopped], sut.current_path());
+    assert_eq!(1, triggered.load(Ordering::SeqCst));
+}
+
+#[test]
+fn given_an_invalid_event_log_replay_should_fail_on_the_first_invalid_event() {
+    let (definition, _triggered) = subject_under_test();
+
+    let sut = definition.replay([Start, Cycle, Stop, Next]);
+
+    assert!(matches!(
+        sut,
+        Err(ReplayFailed { index: 2, source })
+            if matches!(*source, InvalidTransition { event: Stop, current_state: Loop })
+    ));
+}
+
+#[test]
+fn given_an_invalid_start_event_replay_should_fail_on_the_start_event() {
+    let (definition, _triggered) = subject_under_test();
+
+    let sut = definition.replay([Cycle]);
+
+    assert!(matches!(
+        sut,
+        Err(ReplayFailed { index: 0, source }) if matches!(*source, NotAStartEvent { event: Cycle })
+    ));
+}
+
+fn subject_under_test() -> (StateMachineDefinition<State, Event>, Arc<AtomicUsize>) {
+    let triggered = Arc::new(AtomicUsize::new(0));
+
+    let trigger = |triggered: &Arc<AtomicUsize>| {
+        let triggered = triggered.clone();
+
+        move |_event, _previous, _next| { triggered.fetch_add(1, Ordering::SeqCst); }
+    };
+
+    let definition = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .only_trigger(trigger(&triggered))
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .only_trigger(trigger(&triggered))
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .only_trigger(trigger(&triggered))
+        .build().unwrap();
+
+    (definition, triggered)
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Cycle,
+    Next,
+    Start,
+    Stop,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Loop,
+    Started,
+    Stopped,
+}
@@ -0,0 +1,150 @@
+use serde::Deserialize;
+
+use Event::{Cycle, Next, Start, Stop};
+use State::{Loop, Started, Stopped};
+
+use crate::builder::BuilderError::{
+    EndStateTransitions, InvalidStateSchema, NoStartState, NoTransitions, StateAlreadyDefined,
+    TransitionAlreadyDefined, ValidationError,
+};
+use crate::builder::Schema;
+use crate::prelude::*;
+
+#[test]
+fn given_a_schema_it_should_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Started", "start": "Start", "transitions": [
+                { "event": "Cycle", "next": "Loop" }, { "event": "Stop", "next": "Stopped" }
+            ] },
+            { "state": "Loop", "transitions": [{ "event": "Next", "next": "Started" }] },
+            { "state": "Stopped", "end": true }
+        ]
+    }"#).build();
+
+    let mut sut = sut.unwrap().create();
+
+    sut.new_triggers(vec![(Stopped, vec![Box::new(|event, previous, _next| {
+        assert_eq!(Stop, event);
+        assert_eq!(Started, previous);
+    })])]).unwrap();
+
+    assert_eq!(Started, sut.start(Start).unwrap());
+    assert_eq!(Loop, sut.event(Cycle).unwrap());
+    assert_eq!(Started, sut.event(Next).unwrap());
+    assert_eq!(Stopped, sut.event(Stop).unwrap());
+}
+
+#[test]
+fn given_a_schema_with_duplicate_states_should_not_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Started", "start": "Start", "transitions": [{ "event": "Next", "next": "Started" }] },
+            { "state": "Started", "transitions": [{ "event": "Next", "next": "Started" }] }
+        ]
+    }"#).build();
+
+    assert!(matches!(
+        sut,
+        Err(InvalidStateSchema { index: 1, error }) if matches!(*error, StateAlreadyDefined { state: Started })
+    ));
+}
+
+#[test]
+fn given_a_schema_with_duplicate_transitions_should_not_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Started", "start": "Start", "transitions": [
+                { "event": "Next", "next": "Started" }, { "event": "Next", "next": "Stopped" }
+            ] },
+            { "state": "Stopped", "end": true }
+        ]
+    }"#).build();
+
+    assert!(matches!(
+        sut,
+        Err(InvalidStateSchema { index: 0, error })
+            if matches!(*error, TransitionAlreadyDefined { event: Next, existing: Started })
+    ));
+}
+
+#[test]
+fn given_a_schema_with_an_end_state_transition_should_not_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Started", "start": "Start", "transitions": [{ "event": "Stop", "next": "Stopped" }] },
+            { "state": "Stopped", "end": true, "transitions": [{ "event": "Next", "next": "Started" }] }
+        ]
+    }"#).build();
+
+    assert!(matches!(
+        sut,
+        Err(InvalidStateSchema { index: 1, error }) if matches!(*error, EndStateTransitions { state: Stopped })
+    ));
+}
+
+#[test]
+fn given_a_schema_with_an_undefined_state_should_not_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Started", "start": "Start", "transitions": [{ "event": "Stop", "next": "Stopped" }] },
+            { "state": "Stopped", "end": true },
+            { "state": "Cycled", "start": "Cycle", "transitions": [{ "event": "Next", "next": "Loop" }] }
+        ]
+    }"#).build();
+
+    assert!(matches!(
+        sut,
+        Err(InvalidStateSchema { index: 2, error }) if matches!(
+            &*error,
+            ValidationError { undefined_states, unreachable } if undefined_states == &[Loop] && unreachable.is_empty()
+        )
+    ));
+}
+
+#[test]
+fn given_a_schema_without_a_start_state_should_not_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Loop", "transitions": [{ "event": "Next", "next": "Loop" }] }
+        ]
+    }"#).build();
+
+    assert!(matches!(sut, Err(NoStartState)));
+}
+
+#[test]
+fn given_a_schema_without_transitions_should_not_build_fsm() {
+    let sut = schema(r#"{
+        "states": [
+            { "state": "Started", "start": "Start" }
+        ]
+    }"#).build();
+
+    assert!(matches!(
+        sut,
+        Err(InvalidStateSchema { index: 0, error }) if matches!(*error, NoTransitions { state: Started })
+    ));
+}
+
+fn schema(json: &str) -> Schema<State, Event> {
+    serde_json::from_str(json).unwrap()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize)]
+pub enum Event {
+    Cycle,
+    Next,
+    Start,
+    Stop,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Deserialize)]
+pub enum State {
+    #[default]
+    Initial,
+    Cycled,
+    Loop,
+    Started,
+    Stopped,
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use Event::{Next, Start, Stop};
+use State::{Initial, Loop, Started, Stopped, Undefined};
+
+use crate::prelude::*;
+use crate::state_machine::{JournalEntry, JournalOptions, Snapshot, StateMachineOptions};
+use crate::state_machine::StateError::{NothingToUndo, UndefinedStates};
+
+#[test]
+fn given_a_snapshot_of_a_new_fsm_it_should_restore_the_initial_state() {
+    let definition = subject_under_test();
+    let snapshot = definition.create().snapshot();
+
+    assert_eq!(Snapshot { current_state: Initial, history: Vec::new(), previous_state: None }, snapshot);
+
+    let mut sut = definition.restore(&snapshot).unwrap();
+
+    assert!(!sut.is_started());
+    assert_eq!(Started, sut.start(Start).unwrap());
+}
+
+#[test]
+fn given_a_snapshot_of_a_running_fsm_it_should_restore_the_current_state() {
+    let definition = subject_under_test();
+    let mut fsm = definition.create();
+
+    fsm.start(Start).unwrap();
+    fsm.event(Next).unwrap();
+
+    let json = serde_json::to_string(&fsm.snapshot()).unwrap();
+    let snapshot = serde_json::from_str::<Snapshot<State, Event>>(&json).unwrap();
+
+    let mut sut = definition.restore(&snapshot).unwrap();
+
+    assert!(sut.is_started());
+    assert_eq!(Loop, *sut);
+    assert_eq!(Some(Started), sut.previous_state());
+    assert_eq!(Stopped, sut.event(Stop).unwrap());
+}
+
+#[test]
+fn given_a_snapshot_of_a_running_fsm_it_should_restore_the_history() {
+    let definition = subject_under_test();
+    let journal = StateMachineOptions::new().journal(JournalOptions { archive: false, capacity: 4 });
+    let mut fsm = definition.create_with(journal.clone());
+
+    fsm.start(Start).unwrap();
+    fsm.event(Next).unwrap();
+
+    let json = serde_json::to_string(&fsm.snapshot()).unwrap();
+    let snapshot = serde_json::from_str::<Snapshot<State, Event>>(&json).unwrap();
+
+    let mut sut = definition.restore_with(&snapshot, journal).unwrap();
+
+    assert_eq!(fsm.history(), sut.history());
+
+    sut.event(Stop).unwrap();
+
+    assert_eq!(
+        Some(&JournalEntry { event: Stop, from: Loop, sequence: 2, to: Stopped, undone: false }),
+        sut.history().last()
+    );
+}
+
+#[test]
+fn given_a_snapshot_restored_without_a_journal_it_should_not_restore_the_history() {
+    let definition = subject_under_test();
+    let mut fsm = definition
+        .create_with(StateMachineOptions::new().journal(JournalOptions { archive: false, capacity: 4 }));
+
+    fsm.start(Start).unwrap();
+    fsm.event(Next).unwrap();
+
+    let mut sut = definition.restore(&fsm.snapshot()).unwrap();
+
+    assert_eq!(2, fsm.history().len());
+    assert!(sut.history().is_empty());
+    assert!(matches!(sut.undo(), Err(NothingToUndo)));
+}
+
+#[test]
+fn given_a_snapshot_without_a_previous_state_it_should_restore_the_current_state() {
+    let definition = subject_under_test();
+
+    let snapshot = serde_json::from_str::<Snapshot<State, Event>>(
+        r#"{ "current_state": "Loop" }"#
+    ).unwrap();
+
+    let sut = definition.restore(&snapshot).unwrap();
+
+    assert_eq!(Loop, *sut);
+    assert_eq!(None, sut.previous_state());
+}
+
+#[test]
+fn given_a_snapshot_of_an_undefined_state_it_should_not_restore() {
+    let definition = subject_under_test();
+
+    let sut = definition.restore(&Snapshot { current_state: Undefined, history: Vec::new(), previous_state: None });
+
+    assert!(matches!(
+        sut,
+        Err(UndefinedStates { states }) if states == [Undefined]
+    ));
+}
+
+fn subject_under_test() -> StateMachineDefinition<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Event {
+    Next,
+    Start,
+    Stop,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum State {
+    #[default]
+    Initial,
+    Loop,
+    Started,
+    Stopped,
+    Undefined,
+}
@@ -1,14 +1,19 @@
-use std::cell::RefCell;
+#[cfg(not(feature = "sync"))]
+use std::cell::{Cell, RefCell};
 use std::fmt::{Display, Formatter};
-use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 use Event::{Cycle, Next, Start, Stop};
-use State::{Initial, Loop, Started, Stopped, Undefined};
+use State::{Initial, Loop, Paused, Started, Stopped, Undefined};
 
 use crate::prelude::*;
+use crate::state_machine::{Clock, JournalEntry, JournalOptions, Shared, StateMachineOptions};
 use crate::state_machine::StateError::{
-    AlreadyStarted, EndState, InvalidTransition, NotAStartEvent, NotStarted, UndefinedStates
+    AlreadyStarted, EndState, GuardRejected, InvalidTransition, NotAStartEvent, NotStarted, NothingToUndo,
+    QueueOverflow, TriggerFailed, UndefinedStates
 };
+#[cfg(feature = "sync")]
+use sync_cell::{Cell, RefCell};
 
 const DEFINED_TRIGGERS: bool = true;
 const CUSTOM_TRIGGERS: bool = false;
@@ -36,6 +41,13 @@ fn given_a_new_fsm_should_not_start_with_incorrect_event() {
     assert!(matches!(transition, Err(NotAStartEvent { event: Cycle })));
 }
 
+#[test]
+fn given_a_new_fsm_undo_should_fail() {
+    let (mut sut, _) = subject_under_test(DEFINED_TRIGGERS);
+
+    assert!(matches!(sut.undo(), Err(NothingToUndo)));
+}
+
 #[test]
 fn given_a_started_fsm_it_should_not_start_again() {
     let (mut sut, _) = subject_under_test(DEFINED_TRIGGERS);
@@ -275,237 +287,1157 @@ fn given_an_fsm_transitioning_through_states_it_should_be_possible_get_current_s
 }
 
 #[test]
-fn given_an_fsm_with_custom_triggers_should_trigger_on_transition() {
-    let custom_triggered = Rc::new(RefCell::new(false));
+fn given_an_fsm_with_a_journal_it_should_record_the_latest_transitions() {
+    let mut sut = subject_under_test_with_journal(JournalOptions { archive: false, capacity: 2 });
 
-    let (mut sut, triggered) = subject_under_test(CUSTOM_TRIGGERS);
+    assert!(sut.history().is_empty());
 
-    sut.new_triggers(vec![
-        (Started, vec![
-            Box::new({
-                let custom_triggered = custom_triggered.clone();
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+    sut.event(Next).unwrap();
 
-                move |event, previous, next| {
-                    assert_eq!(Start, event);
-                    assert_eq!(Initial, previous);
-                    assert_eq!(Started, next);
+    assert_eq!(
+        vec![
+            JournalEntry { event: Cycle, from: Started, sequence: 1, to: Loop, undone: false },
+            JournalEntry { event: Next, from: Loop, sequence: 2, to: Started, undone: false },
+        ],
+        sut.history()
+    );
+}
 
-                    *custom_triggered.borrow_mut() = true;
-                }
-            })
-        ])
-    ]).unwrap();
+#[test]
+fn given_an_fsm_with_a_journal_undo_should_record_the_undone_transition() {
+    let mut sut = subject_under_test_with_journal(JournalOptions { archive: false, capacity: 2 });
 
     sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+    sut.undo().unwrap();
+
+    assert_eq!(
+        vec![
+            JournalEntry { event: Cycle, from: Started, sequence: 1, to: Loop, undone: false },
+            JournalEntry { event: Cycle, from: Loop, sequence: 2, to: Started, undone: true },
+        ],
+        sut.history()
+    );
+    assert_eq!(sut.current_state(), sut.history().last().unwrap().to);
+}
 
-    assert!(*custom_triggered.borrow());
+#[test]
+fn given_an_fsm_with_an_archived_journal_undoing_the_start_should_record_the_undone_transition() {
+    let mut sut = subject_under_test_with_journal(JournalOptions { archive: true, capacity: 2 });
 
-    let triggered = *triggered.borrow();
+    sut.start(Start).unwrap();
+    sut.undo().unwrap();
 
-    assert_eq!(0, triggered);
+    assert_eq!(
+        vec![vec![JournalEntry { event: Start, from: Initial, sequence: 0, to: Started, undone: false }]],
+        sut.archived_history()
+    );
+    assert_eq!(
+        vec![JournalEntry { event: Start, from: Started, sequence: 1, to: Initial, undone: true }],
+        sut.history()
+    );
+}
 
-    let custom_triggered = *custom_triggered.borrow();
+#[test]
+fn given_an_fsm_with_a_journal_resetting_it_should_clear_the_journal() {
+    let mut sut = subject_under_test_with_journal(JournalOptions { archive: false, capacity: 2 });
 
-    assert!(custom_triggered);
+    sut.start(Start).unwrap();
+    sut.reset();
+
+    assert!(sut.history().is_empty());
+    assert!(sut.archived_history().is_empty());
 }
 
 #[test]
-fn given_an_fsm_with_custom_triggers_for_undefined_states_should_fail_to_customize_trigger() {
-    let (mut sut, _triggered) = subject_under_test(CUSTOM_TRIGGERS);
+fn given_an_fsm_with_a_sub_machine_entering_the_composite_state_should_start_the_sub_machine() {
+    let mut sut = subject_under_test_with_sub_machine();
 
-    let err = sut.new_triggers(vec![
-        (Undefined, vec![Box::new(|_event, _previous, _next| unreachable!())])
-    ]);
+    sut.start(Start).unwrap();
 
-    assert!(matches!(
-        err,
-        Err(UndefinedStates { states }) if states.contains(&Undefined) && states.len() == 1
-    ))
+    assert_eq!(Started, *sut);
+    assert_eq!(vec![Started, Loop], sut.current_path());
 }
 
 #[test]
-fn given_an_fsm_with_cycles_it_should_loop() {
-    let (mut sut, triggered) = subject_under_test(DEFINED_TRIGGERS);
+fn given_an_fsm_with_a_sub_machine_events_should_transition_the_sub_machine_first() {
+    let mut sut = subject_under_test_with_sub_machine();
 
-    assert!(!sut.is_started());
+    sut.start(Start).unwrap();
+
+    assert_eq!(Started, sut.event(Cycle).unwrap());
+    assert_eq!(vec![Started, Stopped], sut.current_path());
+
+    assert_eq!(Loop, sut.event(Cycle).unwrap());
+    assert_eq!(vec![Loop], sut.current_path());
+}
+
+#[test]
+fn given_an_fsm_with_a_sub_machine_resetting_it_should_reset_the_sub_machine() {
+    let mut sut = subject_under_test_with_sub_machine();
 
     sut.start(Start).unwrap();
+    sut.reset();
 
-    assert_eq!(Started, *sut);
-    assert!(sut.is_started());
+    assert_eq!(vec![Initial], sut.current_path());
+}
+
+#[test]
+fn given_an_fsm_with_a_sub_machine_undo_should_restore_the_sub_machine() {
+    let mut sut = subject_under_test_with_sub_machine();
 
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
     sut.event(Cycle).unwrap();
 
-    assert_eq!(Loop, *sut);
+    assert_eq!(Started, sut.undo().unwrap());
+    assert_eq!(vec![Started, Stopped], sut.current_path());
 
-    sut.event(Next).unwrap();
+    assert_eq!(Started, sut.undo().unwrap());
+    assert_eq!(vec![Started, Loop], sut.current_path());
 
-    assert_eq!(Started, *sut);
+    assert_eq!(Initial, sut.undo().unwrap());
+    assert_eq!(vec![Initial], sut.current_path());
+}
 
-    sut.event(Cycle).unwrap();
+#[test]
+fn given_an_fsm_with_a_sub_machine_unhandled_events_should_fall_through_to_the_composite_state() {
+    let mut sut = subject_under_test_with_sub_machine();
 
-    assert_eq!(Loop, *sut);
+    sut.start(Start).unwrap();
+
+    assert_eq!(Stopped, sut.event(Stop).unwrap());
+    assert_eq!(vec![Stopped], sut.current_path());
+}
+
+#[test]
+fn given_an_fsm_with_a_transition_to_the_previous_state_it_should_return_to_the_previous_state() {
+    let mut sut = subject_under_test_with_previous_state();
 
+    sut.start(Start).unwrap();
     sut.event(Next).unwrap();
 
-    assert_eq!(Started, *sut);
+    assert_eq!(Paused, sut.event(Cycle).unwrap());
+    assert_eq!(Loop, sut.event(Next).unwrap());
 
-    sut.event(Stop).unwrap();
+    assert_eq!(Started, sut.event(Next).unwrap());
+    assert_eq!(Paused, sut.event(Cycle).unwrap());
+    assert_eq!(Started, sut.event(Next).unwrap());
+}
 
-    assert_eq!(Stopped, *sut);
-    assert!(sut.is_end());
+#[test]
+fn given_an_fsm_with_an_action_on_a_transition_to_the_previous_state_it_should_invoke_the_action() {
+    let invoked = Shared::new(RefCell::new(Vec::new()));
+    let resumed = invoked.clone();
 
-    let triggered = *triggered.borrow();
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Paused).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Paused).unwrap()
+            .on_transition(Next, move |event, prior, next| resumed.borrow_mut().push((event, prior, next)))
+            .no_triggers()
+            .only_transition_to_previous_on(Next).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create();
 
-    assert_eq!(6, triggered);
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Started, sut.event(Next).unwrap());
+    assert_eq!(vec![(Next, Paused, Started)], *invoked.borrow());
 }
 
 #[test]
-fn given_an_fsm_with_multiple_triggers_events_should_trigger_on_transition() {
-    let (mut sut, triggered) = subject_under_test_multiple_triggers(DEFINED_TRIGGERS);
+fn given_an_fsm_with_a_transition_to_the_previous_state_next_states_should_resolve_the_previous_state() {
+    let mut sut = subject_under_test_with_previous_state();
 
-    assert!(!sut.is_started());
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Some(Started), sut.previous_state());
+    assert_eq!(vec![(&Next, &Started)], sut.next_states().collect::<Vec<_>>());
+}
+
+#[test]
+fn given_an_fsm_with_a_timed_transition_re_entering_the_state_should_restart_the_timeout() {
+    let clock = FakeClock::default();
+    let mut sut = subject_under_test_with_timed_transition(&clock);
 
     sut.start(Start).unwrap();
+    clock.advance(Duration::from_mins(20));
+    sut.event(Next).unwrap();
 
-    assert_eq!(Started, *sut);
-    assert!(sut.is_started());
+    assert_eq!(None, sut.next_deadline());
 
     sut.event(Cycle).unwrap();
 
-    assert_eq!(Loop, *sut);
+    assert_eq!(Some(clock.now() + Duration::from_mins(30)), sut.next_deadline());
+}
 
-    sut.event(Next).unwrap();
+#[test]
+fn given_an_fsm_with_a_timed_transition_tick_should_fire_the_expired_timeout() {
+    let clock = FakeClock::default();
+    let mut sut = subject_under_test_with_timed_transition(&clock);
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Some(clock.now() + Duration::from_mins(30)), sut.next_deadline());
 
+    clock.advance(Duration::from_mins(29));
+
+    assert_eq!(None, sut.tick().unwrap());
     assert_eq!(Started, *sut);
 
-    sut.event(Stop).unwrap();
+    clock.advance(Duration::from_mins(1));
 
-    assert_eq!(Stopped, *sut);
-    assert!(sut.is_end());
+    assert_eq!(Some(Stopped), sut.tick().unwrap());
+    assert_eq!(None, sut.next_deadline());
+}
 
-    let triggered = *triggered.borrow();
+#[test]
+fn given_an_fsm_with_a_timed_transition_tick_should_fire_the_timeout_on_the_composite_state() {
+    let clock = FakeClock::default();
 
-    assert_eq!(8, triggered);
+    let child = StateMachineBuilder::new()
+        .add_start_state(Start, Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap();
+
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .sub_machine(Start, child).unwrap()
+            .no_triggers()
+            .transition_on(Next, Loop).unwrap()
+            .final_transition_after(Duration::from_mins(30), Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Cycle, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create_with(StateMachineOptions::new().clock(clock.clone()));
+
+    sut.start(Start).unwrap();
+    clock.advance(Duration::from_mins(30));
+
+    assert_eq!(Some(Stopped), sut.tick().unwrap());
+    assert!(sut.is_end());
 }
 
 #[test]
-fn given_an_fsm_with_triggers_events_should_trigger_on_transition() {
-    let (mut sut, triggered) = subject_under_test(DEFINED_TRIGGERS);
+fn given_an_fsm_with_actions_clearing_triggers_should_not_clear_actions() {
+    let (mut sut, invoked) = subject_under_test_with_actions();
 
-    assert!(!sut.is_started());
+    sut.clear_triggers();
 
     sut.start(Start).unwrap();
+    sut.event(Stop).unwrap();
 
-    assert_eq!(Started, *sut);
-    assert!(sut.is_started());
+    assert_eq!(vec!["enter started", "exit started", "enter stopped"], *invoked.borrow());
+}
 
-    sut.event(Cycle).unwrap();
+#[test]
+fn given_an_fsm_with_actions_it_should_invoke_exit_transition_and_entry_actions_after_triggers() {
+    let (mut sut, invoked) = subject_under_test_with_actions();
 
-    assert_eq!(Loop, *sut);
+    sut.start(Start).unwrap();
 
-    sut.event(Next).unwrap();
+    assert_eq!(vec!["trigger started", "enter started"], *invoked.borrow());
 
-    assert_eq!(Started, *sut);
+    invoked.borrow_mut().clear();
 
-    sut.event(Stop).unwrap();
+    sut.event(Cycle).unwrap();
 
-    assert_eq!(Stopped, *sut);
-    assert!(sut.is_end());
+    assert_eq!(
+        vec!["trigger loop", "exit started", "transition cycle", "enter loop"],
+        *invoked.borrow()
+    );
 
-    let triggered = *triggered.borrow();
+    invoked.borrow_mut().clear();
 
-    assert_eq!(4, triggered);
+    sut.event(Next).unwrap();
+
+    assert_eq!(vec!["trigger started", "enter started"], *invoked.borrow());
 }
 
 #[test]
-fn given_an_fsm_without_cycles_it_should_end() {
-    let mut sut = subject_under_test_without_cycles();
+fn given_an_fsm_with_actions_a_vetoed_transition_should_not_invoke_its_actions() {
+    let (mut sut, invoked) = subject_under_test_with_actions();
 
-    assert!(!sut.is_started());
+    sut.new_fallible_triggers(vec![
+        (Loop, vec![Box::new(|_event, _previous, _next| Err("loop is unavailable".into()))])
+    ]).unwrap();
 
     sut.start(Start).unwrap();
+    invoked.borrow_mut().clear();
 
+    assert!(matches!(sut.event(Cycle), Err(TriggerFailed { state: Loop, event: Cycle, .. })));
     assert_eq!(Started, *sut);
-    assert!(sut.is_started());
+    assert!(invoked.borrow().is_empty());
+}
 
-    sut.event(Stop).unwrap();
+#[test]
+fn given_an_fsm_with_an_archived_journal_resetting_it_should_archive_the_journal() {
+    let mut sut = subject_under_test_with_journal(JournalOptions { archive: true, capacity: 2 });
 
-    assert_eq!(Stopped, *sut);
-    assert!(sut.is_end());
+    sut.start(Start).unwrap();
+    sut.reset();
+    sut.start(Start).unwrap();
+
+    assert_eq!(
+        vec![vec![JournalEntry { event: Start, from: Initial, sequence: 0, to: Started, undone: false }]],
+        sut.archived_history()
+    );
+    assert_eq!(
+        vec![JournalEntry { event: Start, from: Initial, sequence: 1, to: Started, undone: false }],
+        sut.history()
+    );
 }
 
-fn subject_under_test(
-    expect_trigger: bool
-) -> (impl FiniteStateMachine<State, Event>, Rc<RefCell<u32>>) {
-    let triggered = Rc::new(RefCell::new(0));
+#[test]
+fn given_an_fsm_with_compensating_actions_undo_past_the_start_state_should_reset() {
+    let (mut sut, compensated) = subject_under_test_with_compensating_actions();
 
-    let sut = StateMachineBuilder::new()
-        .add_start_state(Start, Started).unwrap()
-            .only_trigger(trigger_start(triggered.clone(), expect_trigger))
-            .transition_on(Cycle, Loop).unwrap()
-            .final_transition_on(Stop, Stopped).unwrap()
-        .add_state(Loop).unwrap()
-            .only_trigger(trigger_state(triggered.clone(), expect_trigger))
-            .only_transition_on(Next, Started).unwrap()
-        .add_end_state(Stopped).unwrap()
-            .only_trigger(trigger_end(triggered.clone(), expect_trigger))
-        .build().unwrap()
-        .create();
+    sut.start(Start).unwrap();
 
-    (sut, triggered)
+    assert_eq!(Initial, sut.undo().unwrap());
+    assert!(!sut.is_started());
+    assert_eq!(vec!["undo started"], *compensated.borrow());
+    assert!(matches!(sut.undo(), Err(NothingToUndo)));
 }
 
-fn subject_under_test_multiple_triggers(
-    expect_trigger: bool
-) -> (impl FiniteStateMachine<State, Event>, Rc<RefCell<u32>>) {
-    let triggered = Rc::new(RefCell::new(0));
+#[test]
+fn given_an_fsm_with_compensating_actions_undo_should_step_back_through_transitions() {
+    let (mut sut, compensated) = subject_under_test_with_compensating_actions();
 
-    let sut = StateMachineBuilder::new()
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+    sut.event(Next).unwrap();
+    sut.event(Stop).unwrap();
+
+    assert_eq!(Started, sut.undo().unwrap());
+    assert_eq!(Started, *sut);
+    assert_eq!(Some(Loop), sut.previous_state());
+
+    assert_eq!(Loop, sut.undo().unwrap());
+    assert_eq!(Started, sut.undo().unwrap());
+    assert_eq!(Some(Initial), sut.previous_state());
+
+    assert_eq!(vec!["undo stopped", "undo started", "undo loop"], *compensated.borrow());
+}
+
+#[test]
+fn given_an_fsm_with_an_undo_depth_undo_should_discard_the_oldest_transitions() {
+    let mut sut = StateMachineBuilder::new()
         .add_start_state(Start, Started).unwrap()
-            .trigger(trigger_start(triggered.clone(), expect_trigger))
-            .final_trigger(trigger_start(triggered.clone(), expect_trigger))
+            .no_triggers()
             .transition_on(Cycle, Loop).unwrap()
             .final_transition_on(Stop, Stopped).unwrap()
         .add_state(Loop).unwrap()
-            .trigger(trigger_state(triggered.clone(), expect_trigger))
-            .final_trigger(trigger_state(triggered.clone(), expect_trigger))
+            .no_triggers()
             .only_transition_on(Next, Started).unwrap()
         .add_end_state(Stopped).unwrap()
-            .trigger(trigger_end(triggered.clone(), expect_trigger))
-            .final_trigger(trigger_end(triggered.clone(), expect_trigger))
+            .no_triggers()
         .build().unwrap()
-        .create();
+        .create_with(StateMachineOptions::new().undo_depth(2));
 
-    (sut, triggered)
-}
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+    sut.event(Next).unwrap();
 
-fn subject_under_test_without_cycles() -> impl FiniteStateMachine<State, Event> {
-    StateMachineBuilder::new()
-        .add_start_state(Start, Started).unwrap()
-            .no_triggers()
-            .only_transition_on(Stop, Stopped).unwrap()
-        .add_end_state(Stopped).unwrap()
-            .no_triggers()
-        .build().unwrap()
-        .create()
+    assert_eq!(Loop, sut.undo().unwrap());
+    assert_eq!(Started, sut.undo().unwrap());
+    assert_eq!(Some(Initial), sut.previous_state());
+    assert!(matches!(sut.undo(), Err(NothingToUndo)));
 }
 
-fn trigger_end(triggered: Rc<RefCell<u32>>, expect_trigger: bool) -> impl Fn(Event, State, State) {
-    move |event, previous, next| {
-        assert!(expect_trigger, "{}", NO_TRIGGER);
+#[test]
+fn given_an_fsm_with_custom_fallible_triggers_a_failed_trigger_should_veto_transition() {
+    let (mut sut, _triggered) = subject_under_test(CUSTOM_TRIGGERS);
 
-        assert_eq!(Stop, event);
-        assert_eq!(Started, previous);
-        assert_eq!(Stopped, next);
+    sut.new_fallible_triggers(vec![
+        (Loop, vec![Box::new(|_event, _previous, _next| Err("loop is unavailable".into()))])
+    ]).unwrap();
 
-        *triggered.borrow_mut() += 1;
-    }
-}
+    sut.start(Start).unwrap();
 
-fn trigger_start(triggered: Rc<RefCell<u32>>, expect_trigger: bool) -> impl Fn(Event, State, State) {
-    move |event, previous, next| {
-        assert!(expect_trigger, "{}", NO_TRIGGER);
+    let err = sut.event(Cycle);
+
+    assert!(matches!(
+        err,
+        Err(TriggerFailed { state: Loop, event: Cycle, source })
+            if source.to_string() == "loop is unavailable"
+    ));
+
+    assert_eq!(Started, *sut);
+}
+
+#[test]
+fn given_an_fsm_with_custom_triggers_should_trigger_on_transition() {
+    let custom_triggered = Shared::new(RefCell::new(false));
+
+    let (mut sut, triggered) = subject_under_test(CUSTOM_TRIGGERS);
+
+    sut.new_triggers(vec![
+        (Started, vec![
+            Box::new({
+                let custom_triggered = custom_triggered.clone();
+
+                move |event, previous, next| {
+                    assert_eq!(Start, event);
+                    assert_eq!(Initial, previous);
+                    assert_eq!(Started, next);
+
+                    *custom_triggered.borrow_mut() = true;
+                }
+            })
+        ])
+    ]).unwrap();
+
+    sut.start(Start).unwrap();
+
+    assert!(*custom_triggered.borrow());
+
+    let triggered = *triggered.borrow();
+
+    assert_eq!(0, triggered);
+
+    let custom_triggered = *custom_triggered.borrow();
+
+    assert!(custom_triggered);
+}
+
+#[test]
+fn given_an_fsm_with_custom_and_fallible_triggers_both_should_trigger_on_transition() {
+    let custom_triggered = Shared::new(RefCell::new(0));
+
+    let (mut sut, triggered) = subject_under_test(CUSTOM_TRIGGERS);
+
+    sut.new_triggers(vec![
+        (Started, vec![
+            Box::new({
+                let custom_triggered = custom_triggered.clone();
+
+                move |_event, _previous, _next| *custom_triggered.borrow_mut() += 1
+            })
+        ])
+    ]).unwrap();
+
+    sut.new_fallible_triggers(vec![
+        (Started, vec![
+            Box::new({
+                let custom_triggered = custom_triggered.clone();
+
+                move |_event, _previous, _next| {
+                    *custom_triggered.borrow_mut() += 1;
+
+                    Ok(())
+                }
+            })
+        ]),
+        (Loop, vec![Box::new(|_event, _previous, _next| Err("loop is unavailable".into()))])
+    ]).unwrap();
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(2, *custom_triggered.borrow());
+    assert!(matches!(sut.event(Cycle), Err(TriggerFailed { state: Loop, event: Cycle, .. })));
+    assert_eq!(0, *triggered.borrow());
+}
+
+#[test]
+fn given_an_fsm_with_custom_triggers_for_undefined_states_should_fail_to_customize_trigger() {
+    let (mut sut, _triggered) = subject_under_test(CUSTOM_TRIGGERS);
+
+    let err = sut.new_triggers(vec![
+        (Undefined, vec![Box::new(|_event, _previous, _next| unreachable!())])
+    ]);
+
+    assert!(matches!(
+        err,
+        Err(UndefinedStates { states }) if states.contains(&Undefined) && states.len() == 1
+    ))
+}
+
+#[test]
+fn given_an_fsm_with_cycles_it_should_loop() {
+    let (mut sut, triggered) = subject_under_test(DEFINED_TRIGGERS);
+
+    assert!(!sut.is_started());
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Started, *sut);
+    assert!(sut.is_started());
+
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Loop, *sut);
+
+    sut.event(Next).unwrap();
+
+    assert_eq!(Started, *sut);
+
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Loop, *sut);
+
+    sut.event(Next).unwrap();
+
+    assert_eq!(Started, *sut);
+
+    sut.event(Stop).unwrap();
+
+    assert_eq!(Stopped, *sut);
+    assert!(sut.is_end());
+
+    let triggered = *triggered.borrow();
+
+    assert_eq!(6, triggered);
+}
+
+#[test]
+fn given_an_fsm_with_deferred_events_it_should_discard_an_event_no_longer_deferred() {
+    let mut sut = subject_under_test_with_deferred_events();
+
+    sut.start(Start).unwrap();
+    sut.event(Stop).unwrap();
+
+    assert_eq!(Paused, sut.event(Next).unwrap());
+    assert!(sut.deferred_events().is_empty());
+    assert_eq!(Loop, sut.event(Cycle).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_deferred_events_undo_should_discard_the_deferred_events() {
+    let mut sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .defer(Stop)
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Next, Paused).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_state(Paused).unwrap()
+            .defer(Stop)
+            .no_triggers()
+            .only_transition_on(Cycle, Loop).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create();
+
+    sut.start(Start).unwrap();
+    sut.event(Next).unwrap();
+
+    assert_eq!(Paused, sut.event(Stop).unwrap());
+    assert_eq!(Started, sut.undo().unwrap());
+    assert_eq!(Loop, sut.event(Cycle).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_deferred_events_it_should_retry_a_deferred_event_after_a_transition() {
+    let mut sut = subject_under_test_with_deferred_events();
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Started, sut.event(Stop).unwrap());
+    assert_eq!(vec![Stop], sut.deferred_events());
+    assert!(!sut.next_states().any(|(event, _)| *event == Stop));
+    assert_eq!(Stopped, sut.event(Cycle).unwrap());
+    assert_eq!(Some(Loop), sut.previous_state());
+}
+
+#[test]
+fn given_an_fsm_with_fallible_triggers_a_failed_trigger_should_veto_transition() {
+    let (mut sut, available) = subject_under_test_with_fallible_triggers();
+
+    sut.start(Start).unwrap();
+
+    assert!(matches!(sut.event(Cycle), Err(TriggerFailed { state: Loop, event: Cycle, .. })));
+    assert_eq!(Started, *sut);
+
+    available.set(true);
+
+    assert_eq!(Loop, sut.event(Cycle).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_global_transitions_explicit_transitions_should_take_precedence() {
+    let mut sut = subject_under_test_with_global_transitions();
+
+    sut.start(Start).unwrap();
+
+    assert!(sut.next_states().any(|edge| edge == (&Stop, &Stopped)));
+    assert_eq!(Loop, sut.event(Cycle).unwrap());
+    assert_eq!(Paused, sut.event(Cycle).unwrap());
+    assert_eq!(Paused, sut.event(Cycle).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_group_transitions_they_should_take_precedence_over_global_transitions() {
+    let mut sut = subject_under_test_with_global_transitions();
+
+    sut.start(Start).unwrap();
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Started, sut.event(Stop).unwrap());
+    assert_eq!(Stopped, sut.event(Stop).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_guarded_transitions_it_should_fall_back_to_an_unguarded_transition() {
+    let (mut sut, permit) = subject_under_test_with_guards();
+
+    sut.start(Start).unwrap();
+
+    permit.set(false);
+
+    assert_eq!(Stopped, sut.event(Stop).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_guarded_transitions_it_should_include_guarded_next_states() {
+    let (mut sut, _permit) = subject_under_test_with_guards();
+
+    sut.start(Start).unwrap();
+
+    let expected = [
+        (&Cycle, &Loop), (&Next, &Loop), (&Next, &Stopped), (&Stop, &Loop), (&Stop, &Stopped)
+    ];
+
+    assert_eq!(expected.len(), sut.next_states().count());
+    assert!(sut.next_states().all(|itm| expected.contains(&itm)));
+}
+
+#[test]
+fn given_an_fsm_with_guarded_transitions_it_should_not_transition_if_no_guard_permits() {
+    let (mut sut, permit) = subject_under_test_with_guards();
+
+    sut.start(Start).unwrap();
+
+    permit.set(false);
+
+    assert!(
+        matches!(sut.event(Cycle),
+            Err(GuardRejected { event: Cycle, current_state: Started }))
+    );
+    assert_eq!(Started, *sut);
+}
+
+#[test]
+fn given_an_fsm_with_guarded_transitions_it_should_take_the_first_permitted_transition() {
+    let (mut sut, permit) = subject_under_test_with_guards();
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Loop, sut.event(Next).unwrap());
+    assert_eq!(Started, sut.event(Next).unwrap());
+
+    permit.set(false);
+
+    assert_eq!(Stopped, sut.event(Next).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_multiple_triggers_events_should_trigger_on_transition() {
+    let (mut sut, triggered) = subject_under_test_multiple_triggers(DEFINED_TRIGGERS);
+
+    assert!(!sut.is_started());
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Started, *sut);
+    assert!(sut.is_started());
+
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Loop, *sut);
+
+    sut.event(Next).unwrap();
+
+    assert_eq!(Started, *sut);
+
+    sut.event(Stop).unwrap();
+
+    assert_eq!(Stopped, *sut);
+    assert!(sut.is_end());
+
+    let triggered = *triggered.borrow();
+
+    assert_eq!(8, triggered);
+}
+
+#[test]
+fn given_an_fsm_with_payload_triggers_events_should_deliver_default_payload() {
+    let (mut sut, delivered) = subject_under_test_with_payload();
+
+    sut.start(Start).unwrap();
+    sut.event(Stop).unwrap();
+
+    assert_eq!(vec![String::new(), String::new()], *delivered.borrow());
+}
+
+#[test]
+fn given_an_fsm_with_payload_triggers_events_should_deliver_payload() {
+    let (mut sut, delivered) = subject_under_test_with_payload();
+
+    sut.start_with(Start, String::from("started")).unwrap();
+
+    assert_eq!(Started, *sut);
+
+    sut.event_with(Stop, String::from("stopped")).unwrap();
+
+    assert_eq!(Stopped, *sut);
+    assert_eq!(vec![String::from("started"), String::from("stopped")], *delivered.borrow());
+}
+
+#[test]
+fn given_an_fsm_with_queued_triggers_events_should_process_follow_up_events_before_returning() {
+    let mut sut = subject_under_test_with_queued_triggers(false, 64);
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Paused, sut.event(Cycle).unwrap());
+    assert_eq!(Some(Loop), sut.previous_state());
+}
+
+#[test]
+fn given_an_fsm_with_queued_triggers_follow_up_events_exceeding_the_max_depth_should_fail() {
+    let mut sut = subject_under_test_with_queued_triggers(true, 3);
+
+    sut.start(Start).unwrap();
+
+    assert!(matches!(sut.event(Cycle), Err(QueueOverflow { event: Cycle, max_depth: 3 })));
+    assert_eq!(Paused, *sut);
+    assert_eq!(Stopped, sut.event(Stop).unwrap());
+}
+
+#[test]
+fn given_an_fsm_with_triggers_events_should_trigger_on_transition() {
+    let (mut sut, triggered) = subject_under_test(DEFINED_TRIGGERS);
+
+    assert!(!sut.is_started());
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Started, *sut);
+    assert!(sut.is_started());
+
+    sut.event(Cycle).unwrap();
+
+    assert_eq!(Loop, *sut);
+
+    sut.event(Next).unwrap();
+
+    assert_eq!(Started, *sut);
+
+    sut.event(Stop).unwrap();
+
+    assert_eq!(Stopped, *sut);
+    assert!(sut.is_end());
+
+    let triggered = *triggered.borrow();
+
+    assert_eq!(4, triggered);
+}
+
+#[test]
+fn given_an_fsm_without_cycles_it_should_end() {
+    let mut sut = subject_under_test_without_cycles();
+
+    assert!(!sut.is_started());
+
+    sut.start(Start).unwrap();
+
+    assert_eq!(Started, *sut);
+    assert!(sut.is_started());
+
+    sut.event(Stop).unwrap();
+
+    assert_eq!(Stopped, *sut);
+    assert!(sut.is_end());
+}
+
+fn subject_under_test(
+    expect_trigger: bool
+) -> (impl FiniteStateMachine<State, Event>, Shared<RefCell<u32>>) {
+    let triggered = Shared::new(RefCell::new(0));
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .only_trigger(trigger_start(triggered.clone(), expect_trigger))
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .only_trigger(trigger_state(triggered.clone(), expect_trigger))
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .only_trigger(trigger_end(triggered.clone(), expect_trigger))
+        .build().unwrap()
+        .create();
+
+    (sut, triggered)
+}
+
+fn subject_under_test_with_actions(
+) -> (impl FiniteStateMachine<State, Event>, Shared<RefCell<Vec<&'static str>>>) {
+    let invoked = Shared::new(RefCell::new(Vec::new()));
+
+    let record = |invoked: &Shared<RefCell<Vec<&'static str>>>, label: &'static str| {
+        let invoked = invoked.clone();
+
+        move |_event, _previous, _next| invoked.borrow_mut().push(label)
+    };
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .on_entry(record(&invoked, "enter started"))
+            .on_exit(record(&invoked, "exit started"))
+            .on_transition(Cycle, record(&invoked, "transition cycle"))
+            .only_trigger(record(&invoked, "trigger started"))
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .on_entry(record(&invoked, "enter loop"))
+            .only_trigger(record(&invoked, "trigger loop"))
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .on_entry(record(&invoked, "enter stopped"))
+            .no_triggers()
+        .build().unwrap()
+        .create();
+
+    (sut, invoked)
+}
+
+fn subject_under_test_multiple_triggers(
+    expect_trigger: bool
+) -> (impl FiniteStateMachine<State, Event>, Shared<RefCell<u32>>) {
+    let triggered = Shared::new(RefCell::new(0));
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .trigger(trigger_start(triggered.clone(), expect_trigger))
+            .final_trigger(trigger_start(triggered.clone(), expect_trigger))
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .trigger(trigger_state(triggered.clone(), expect_trigger))
+            .final_trigger(trigger_state(triggered.clone(), expect_trigger))
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .trigger(trigger_end(triggered.clone(), expect_trigger))
+            .final_trigger(trigger_end(triggered.clone(), expect_trigger))
+        .build().unwrap()
+        .create();
+
+    (sut, triggered)
+}
+
+fn subject_under_test_with_deferred_events() -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .defer(Stop)
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Next, Paused).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_state(Paused).unwrap()
+            .no_triggers()
+            .only_transition_on(Cycle, Loop).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create()
+}
+
+fn subject_under_test_with_fallible_triggers(
+) -> (impl FiniteStateMachine<State, Event>, Shared<Cell<bool>>) {
+    let available = Shared::new(Cell::new(false));
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .try_trigger({
+                let available = available.clone();
+
+                move |_event, _previous, _next| if available.get() {
+                    Ok(())
+                } else {
+                    Err("loop is unavailable".into())
+                }
+            })
+            .final_trigger(|_event, _previous, _next| {})
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create();
+
+    (sut, available)
+}
+
+fn subject_under_test_with_global_transitions() -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Cycle, Loop).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .add_state(Paused).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Loop).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .add_global_transition(Stop, Stopped).unwrap()
+        .add_global_transition(Cycle, Paused).unwrap()
+        .add_group_transition(&[Loop, Paused], Stop, Started)
+        .build().unwrap()
+        .create()
+}
+
+fn subject_under_test_with_guards() -> (impl FiniteStateMachine<State, Event>, Shared<Cell<bool>>) {
+    let permit = Shared::new(Cell::new(true));
+
+    let permitted = |permit: &Shared<Cell<bool>>| {
+        let permit = permit.clone();
+
+        move |_event, _state| permit.get()
+    };
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on_if(Next, Loop, permitted(&permit))
+            .transition_on_if(Next, Stopped, |_event, _state| true)
+            .transition_on_if(Cycle, Loop, permitted(&permit))
+            .transition_on_if(Stop, Loop, permitted(&permit))
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create();
+
+    (sut, permit)
+}
+
+fn subject_under_test_with_payload(
+) -> (impl FiniteStateMachine<State, Event, String>, Shared<RefCell<Vec<String>>>) {
+    let delivered = Shared::new(RefCell::new(Vec::new()));
+
+    let deliver = |delivered: &Shared<RefCell<Vec<String>>>| {
+        let delivered = delivered.clone();
+
+        move |_event, _previous, _next, payload: &String| delivered.borrow_mut().push(payload.clone())
+    };
+
+    let sut = StateMachineBuilder::with_payload()
+        .add_start_state(Start, Started).unwrap()
+            .only_trigger_with(deliver(&delivered))
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .trigger(trigger_end(Shared::new(RefCell::new(0)), DEFINED_TRIGGERS))
+            .final_trigger_with(deliver(&delivered))
+        .build().unwrap()
+        .create();
+
+    (sut, delivered)
+}
+
+fn subject_under_test_with_compensating_actions(
+) -> (impl FiniteStateMachine<State, Event>, Shared<RefCell<Vec<&'static str>>>) {
+    let compensated = Shared::new(RefCell::new(Vec::new()));
+
+    let record = |compensated: &Shared<RefCell<Vec<&'static str>>>, label: &'static str| {
+        let compensated = compensated.clone();
+
+        move |_event, _undone, _restored| compensated.borrow_mut().push(label)
+    };
+
+    let sut = StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .on_undo(record(&compensated, "undo started"))
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .on_undo(record(&compensated, "undo loop"))
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .on_undo(record(&compensated, "undo stopped"))
+            .no_triggers()
+        .build().unwrap()
+        .create();
+
+    (sut, compensated)
+}
+
+fn subject_under_test_with_journal(options: JournalOptions) -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create_with(StateMachineOptions::new().journal(options))
+}
+
+fn subject_under_test_with_previous_state() -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Next, Loop).unwrap()
+            .transition_on(Cycle, Paused).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .transition_on(Next, Started).unwrap()
+            .final_transition_on(Cycle, Paused).unwrap()
+        .add_state(Paused).unwrap()
+            .no_triggers()
+            .only_transition_to_previous_on(Next).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create()
+}
+
+fn subject_under_test_with_queued_triggers(
+    looping: bool,
+    max_depth: usize,
+) -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .only_queued_trigger(|_event, _prior, _next, queue| queue.post(Next))
+            .only_transition_on(Next, Paused).unwrap()
+        .add_state(Paused).unwrap()
+            .only_queued_trigger(move |_event, _prior, _next, queue| if looping { queue.post(Cycle) })
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create_with(StateMachineOptions::new().max_depth(max_depth))
+}
+
+fn subject_under_test_with_timed_transition(clock: &FakeClock) -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .transition_on(Next, Loop).unwrap()
+            .final_transition_after(Duration::from_mins(30), Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Cycle, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create_with(StateMachineOptions::new().clock(clock.clone()))
+}
+
+fn subject_under_test_with_sub_machine() -> impl FiniteStateMachine<State, Event> {
+    let child = StateMachineBuilder::new()
+        .add_start_state(Start, Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Cycle, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap();
+
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .sub_machine(Start, child).unwrap()
+            .no_triggers()
+            .transition_on(Cycle, Loop).unwrap()
+            .final_transition_on(Stop, Stopped).unwrap()
+        .add_state(Loop).unwrap()
+            .no_triggers()
+            .only_transition_on(Next, Started).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create()
+}
+
+fn subject_under_test_without_cycles() -> impl FiniteStateMachine<State, Event> {
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .no_triggers()
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .no_triggers()
+        .build().unwrap()
+        .create()
+}
+
+#[derive(Clone)]
+struct FakeClock {
+    now: Shared<Cell<Instant>>,
+}
+
+impl FakeClock {
+    fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        Self { now: Shared::new(Cell::new(Instant::now())) }
+    }
+}
+
+fn trigger_end(triggered: Shared<RefCell<u32>>, expect_trigger: bool) -> impl Fn(Event, State, State) {
+    move |event, previous, next| {
+        assert!(expect_trigger, "{}", NO_TRIGGER);
+
+        assert_eq!(Stop, event);
+        assert_eq!(Started, previous);
+        assert_eq!(Stopped, next);
+
+        *triggered.borrow_mut() += 1;
+    }
+}
+
+fn trigger_start(triggered: Shared<RefCell<u32>>, expect_trigger: bool) -> impl Fn(Event, State, State) {
+    move |event, previous, next| {
+        assert!(expect_trigger, "{}", NO_TRIGGER);
 
         match event {
             Next => assert_eq!(Loop, previous),
@@ -519,7 +1451,7 @@ fn trigger_start(triggered: Rc<RefCell<u32>>, expect_trigger: bool) -> impl Fn(E
     }
 }
 
-fn trigger_state(triggered: Rc<RefCell<u32>>, expect_trigger: bool) -> impl Fn(Event, State, State) {
+fn trigger_state(triggered: Shared<RefCell<u32>>, expect_trigger: bool) -> impl Fn(Event, State, State) {
     move |event, previous, next| {
         assert!(expect_trigger, "{}", NO_TRIGGER);
 
@@ -544,6 +1476,7 @@ pub enum State {
     #[default]
     Initial,
     Loop,
+    Paused,
     Started,
     Stopped,
     Undefined
@@ -555,8 +1488,48 @@ impl Display for State {
             Initial => "Initial",
             Started => "Started",
             Loop => "Loop",
+            Paused => "Paused",
             Stopped => "Stopped",
             Undefined => "Undefined",
         }))
     }
-}
\ No newline at end of file
+}
+
+// the `sync` feature shares state with triggers through an `Arc`, these stand-ins of
+// `Cell` and `RefCell` share state through a `Mutex` instead
+#[cfg(feature = "sync")]
+mod sync_cell {
+    use std::sync::{Mutex, MutexGuard};
+
+    pub struct Cell<T>(Mutex<T>);
+
+    impl<T: Copy> Cell<T> {
+        pub const fn new(value: T) -> Self {
+            Self(Mutex::new(value))
+        }
+
+        pub fn get(&self) -> T {
+            *self.0.lock().unwrap()
+        }
+
+        pub fn set(&self, value: T) {
+            *self.0.lock().unwrap() = value;
+        }
+    }
+
+    pub struct RefCell<T>(Mutex<T>);
+
+    impl<T> RefCell<T> {
+        pub const fn new(value: T) -> Self {
+            Self(Mutex::new(value))
+        }
+
+        pub fn borrow(&self) -> MutexGuard<'_, T> {
+            self.0.lock().unwrap()
+        }
+
+        pub fn borrow_mut(&self) -> MutexGuard<'_, T> {
+            self.0.lock().unwrap()
+        }
+    }
+}
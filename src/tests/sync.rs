@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+
+use Event::{Start, Stop};
+use State::{Initial, Started, Stopped};
+
+use crate::prelude::*;
+
+#[test]
+fn given_a_sync_definition_it_should_be_shareable_across_threads() {
+    let triggered = Arc::new(AtomicU32::new(0));
+    let definition = subject_under_test(triggered.clone());
+
+    let handles = (0..4)
+        .map(|_| {
+            let definition = definition.clone();
+
+            thread::spawn(move || {
+                let mut sut = definition.create();
+
+                sut.start(Start).unwrap();
+                sut.event(Stop).unwrap()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert_eq!(Stopped, handle.join().unwrap());
+    }
+
+    assert_eq!(8, triggered.load(Ordering::SeqCst));
+}
+
+#[test]
+fn given_a_sync_fsm_it_should_be_sendable_to_another_thread() {
+    let triggered = Arc::new(AtomicU32::new(0));
+    let mut sut = subject_under_test(triggered.clone()).create();
+
+    assert_eq!(Initial, *sut);
+
+    sut.start(Start).unwrap();
+
+    let handle = thread::spawn(move || sut.event(Stop).unwrap());
+
+    assert_eq!(Stopped, handle.join().unwrap());
+    assert_eq!(2, triggered.load(Ordering::SeqCst));
+}
+
+fn subject_under_test(triggered: Arc<AtomicU32>) -> StateMachineDefinition<State, Event> {
+    let trigger = move |_event, _previous, _next| {
+        triggered.fetch_add(1, Ordering::SeqCst);
+    };
+
+    StateMachineBuilder::new()
+        .add_start_state(Start, Started).unwrap()
+            .only_trigger(trigger.clone())
+            .only_transition_on(Stop, Stopped).unwrap()
+        .add_end_state(Stopped).unwrap()
+            .only_trigger(trigger)
+        .build().unwrap()
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Event {
+    Start,
+    Stop,
+}
+
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+pub enum State {
+    #[default]
+    Initial,
+    Started,
+    Stopped,
+}
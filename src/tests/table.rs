@@ -0,0 +1,139 @@
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use Event::{Coin, Off, On, Push};
+use State::{Initial, Locked, Stopped, Unlocked};
+
+use crate::prelude::*;
+use crate::state_machine::StateError::StaticTriggers;
+use crate::state_table;
+use crate::table::{Indexed, StateTable, TableError};
+
+static UNLOCKED: AtomicUsize = AtomicUsize::new(0);
+
+const TURNSTILE: StateTable<State, Event, 4, 4> = state_table! {
+    Initial;
+    [*] --On--> Locked;
+    Locked --Coin--> Unlocked;
+    Locked --Push--> Locked;
+    Locked --Off--> Stopped;
+    Unlocked --Coin--> Unlocked;
+    Unlocked --Push--> Locked;
+    Stopped --> [*];
+    Unlocked => unlocked;
+};
+
+#[test]
+fn given_a_const_state_table_it_should_transition_through_the_defined_states() {
+    let mut sut = TURNSTILE.create();
+
+    assert_eq!(Initial, *sut);
+    assert_eq!(Ok(Locked), sut.try_start(On));
+    assert_eq!(Ok(Unlocked), sut.try_event(Coin));
+    assert_eq!(Ok(Unlocked), sut.try_event(Coin));
+    assert_eq!(Some(Locked), sut.previous_state());
+    assert_eq!(Ok(Locked), sut.try_event(Push));
+    assert_eq!(Ok(Stopped), sut.try_event(Off));
+    assert!(sut.is_end());
+}
+
+#[test]
+fn given_a_const_state_table_it_should_invoke_the_trigger_of_an_entered_state() {
+    let mut sut = TURNSTILE.create();
+    let triggered = UNLOCKED.load(Ordering::SeqCst);
+
+    sut.try_start(On).unwrap();
+    sut.try_event(Coin).unwrap();
+    sut.try_event(Push).unwrap();
+
+    // other tests enter the unlocked state concurrently
+    assert!(UNLOCKED.load(Ordering::SeqCst) > triggered);
+}
+
+#[test]
+fn given_a_started_table_fsm_it_should_not_start_again() {
+    let mut sut = TURNSTILE.create();
+
+    sut.try_start(On).unwrap();
+
+    assert_eq!(Err(TableError::AlreadyStarted { current_state: Locked }), sut.try_start(On));
+}
+
+#[test]
+fn given_a_table_fsm_invalid_events_should_fail() {
+    let mut sut = TURNSTILE.create();
+
+    assert_eq!(Err(TableError::NotStarted), sut.try_event(Coin));
+    assert_eq!(Err(TableError::NotAStartEvent { event: Coin }), sut.try_start(Coin));
+
+    sut.try_start(On).unwrap();
+
+    assert_eq!(Err(TableError::InvalidTransition { event: On, current_state: Locked }), sut.try_event(On));
+
+    sut.try_event(Off).unwrap();
+
+    assert_eq!(Err(TableError::EndState { end: Stopped }), sut.try_event(Coin));
+}
+
+#[test]
+fn given_a_table_fsm_it_should_be_a_finite_state_machine() {
+    let mut sut = TURNSTILE.create();
+
+    assert!(matches!(FiniteStateMachine::start(&mut sut, On), Ok(Locked)));
+    assert!(matches!(FiniteStateMachine::event(&mut sut, Coin), Ok(Unlocked)));
+
+    let mut next_states = sut.next_states().map(|(event, state)| (*event, *state)).collect::<Vec<_>>();
+
+    next_states.sort_by_key(|(event, _)| event.index());
+
+    assert_eq!(vec![(Push, Locked), (Coin, Unlocked)], next_states);
+    assert!(matches!(sut.new_triggers(Vec::new()), Err(StaticTriggers)));
+    assert_eq!(Unlocked, FiniteStateMachine::reset(&mut sut));
+    assert!(!FiniteStateMachine::is_started(&sut));
+}
+
+#[test]
+fn given_no_default_features_the_crate_should_build() {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let target_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/target/no_std");
+
+    let status = Command::new(cargo)
+        .args(["build", "--all-targets", "--no-default-features", "--target-dir", target_dir])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .status()
+        .expect("cargo should build the crate");
+
+    assert!(status.success());
+}
+
+fn unlocked(_event: Event, _prior: State, _next: State) {
+    UNLOCKED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Event {
+    On,
+    Push,
+    Coin,
+    Off,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum State {
+    Initial,
+    Locked,
+    Unlocked,
+    Stopped,
+}
+
+impl Indexed for Event {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Indexed for State {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
@@ -0,0 +1,168 @@
+use proc_macro2::Span;
+use syn::{Attribute, Data, DeriveInput, Fields, Generics, Ident, Path, Token, Visibility};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// The state machine definition of an annotated `State` enum
+pub struct Definition {
+    /// Event type of the state machine, _defaults to `Event`_
+    pub event: Path,
+
+    /// Generics of the `State` enum, which are not supported
+    pub generics: Generics,
+
+    /// The `State` enum
+    pub ident: Ident,
+
+    /// The `#[default]` variant, _the initial state of the state machine_
+    pub initial: Option<Ident>,
+
+    /// All the variants of the `State` enum, in declaration order
+    pub variants: Vec<Variant>,
+
+    /// Visibility of the `State` enum
+    pub vis: Visibility,
+}
+
+/// A variant of an annotated `State` enum
+pub struct Variant {
+    /// `#[end]` attribute of the variant
+    pub end: Option<Span>,
+
+    /// Fields of the variant, _only unit variants can be states_
+    pub fields: Option<Span>,
+
+    /// The variant
+    pub ident: Ident,
+
+    /// Event of the `#[start(on = Event)]` attribute of the variant
+    pub start: Option<Path>,
+
+    /// `#[transition(Event => State)]` attributes of the variant
+    pub transitions: Vec<Transition>,
+}
+
+/// A `Event => State` transition of a `#[transition]` attribute
+pub struct Transition {
+    /// Event to transition on
+    pub event: Path,
+
+    /// State to transition to
+    pub state: Ident,
+}
+
+impl Variant {
+    /// Whether the variant is annotated as a state of the state machine
+    pub const fn is_defined(&self) -> bool {
+        self.start.is_some() || self.end.is_some() || !self.transitions.is_empty()
+    }
+}
+
+impl Parse for Transition {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let event = input.parse()?;
+
+        input.parse::<Token![=>]>()?;
+
+        Ok(Self { event, state: input.parse()? })
+    }
+}
+
+impl TryFrom<DeriveInput> for Definition {
+    type Error = syn::Error;
+
+    fn try_from(input: DeriveInput) -> syn::Result<Self> {
+        let Data::Enum(data) = input.data else {
+            return Err(syn::Error::new(input.ident.span(), "StateMachine can only be derived for enums"));
+        };
+
+        let mut event = None;
+
+        for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("state_machine")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("event") {
+                    event = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `event = Event`"))
+                }
+            })?;
+        }
+
+        let initial = data.variants.iter()
+            .find(|variant| variant.attrs.iter().any(|attr| attr.path().is_ident("default")))
+            .map(|variant| variant.ident.clone());
+
+        let variants = data.variants.into_iter()
+            .map(|variant| parse_variant(variant.ident, &variant.attrs, &variant.fields))
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Self {
+            event: event.unwrap_or_else(|| Ident::new("Event", Span::call_site()).into()),
+            generics: input.generics,
+            ident: input.ident,
+            initial,
+            variants,
+            vis: input.vis,
+        })
+    }
+}
+
+/// Parses the state machine attributes of a variant
+///
+/// # Arguments
+///
+/// * _`ident`_ - the variant
+/// * _`attrs`_ - attributes of the variant
+/// * _`fields`_ - fields of the variant
+///
+/// # Errors
+///
+/// Returns an error for malformed or repeated `#[start]` and `#[end]` attributes
+fn parse_variant(ident: Ident, attrs: &[Attribute], fields: &Fields) -> syn::Result<Variant> {
+    let mut variant = Variant {
+        end: None,
+        fields: (!matches!(fields, Fields::Unit)).then(|| fields.span()),
+        ident,
+        start: None,
+        transitions: Vec::new(),
+    };
+
+    for attr in attrs {
+        if attr.path().is_ident("start") {
+            if variant.start.is_some() {
+                return Err(syn::Error::new(attr.span(), format!("{} state has already been defined", variant.ident)));
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("on") {
+                    variant.start = Some(meta.value()?.parse()?);
+
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `on = Event`"))
+                }
+            })?;
+
+            if variant.start.is_none() {
+                return Err(syn::Error::new(attr.span(), "expected `#[start(on = Event)]`"));
+            }
+        } else if attr.path().is_ident("end") {
+            attr.meta.require_path_only()?;
+
+            if variant.end.is_some() {
+                return Err(syn::Error::new(attr.span(), format!("{} state has already been defined", variant.ident)));
+            }
+
+            variant.end = Some(attr.span());
+        } else if attr.path().is_ident("transition") {
+            variant.transitions.extend(
+                attr.parse_args_with(Punctuated::<Transition, Token![,]>::parse_terminated)?
+            );
+        }
+    }
+
+    Ok(variant)
+}
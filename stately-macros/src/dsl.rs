@@ -0,0 +1,240 @@
+use std::fmt::{Display, Formatter};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{bracketed, Ident, Path, Token, token};
+use syn::parse::{Parse, ParseStream};
+
+use crate::definition::{Transition, Variant};
+use crate::expand::expand_builder;
+use crate::validation::{combine, validate_states};
+
+/// The lines of a `state_machine!` definition
+pub struct Dsl {
+    lines: Vec<Line>,
+}
+
+/// A line of a `state_machine!` definition
+pub enum Line {
+    /// `State --> [*]`, _an end state_
+    End {
+        state: Ident,
+        arrow: (Token![-], Token![->]),
+        end: Pseudo,
+    },
+
+    /// `[*] --Event--> State`, _a start state_
+    Start {
+        start: Pseudo,
+        event: (Token![-], Token![-], Path, Token![-], Token![->]),
+        state: Ident,
+    },
+
+    /// `State --Event--> State`, _a transition_
+    Transition {
+        from: Ident,
+        event: (Token![-], Token![-], Path, Token![-], Token![->]),
+        to: Ident,
+    },
+}
+
+/// The `[*]` pseudo state of a `state_machine!` definition
+pub struct Pseudo {
+    bracket: token::Bracket,
+    star: Token![*],
+}
+
+impl Dsl {
+    /// Expands the lines of a `state_machine!` definition into builder calls
+    ///
+    /// # Results
+    ///
+    /// Returns the tokens of an expression that evaluates to the result of the `build`
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation errors of the definition combined, duplicate states and
+    /// duplicate transitions point at the offending line
+    pub fn expand(&self) -> syn::Result<TokenStream> {
+        let mut errors = Vec::new();
+        let mut states = Vec::<Variant>::new();
+
+        for line in &self.lines {
+            if let Err(error) = define(&mut states, line) {
+                errors.push(error);
+            }
+        }
+
+        errors.extend(validate_states(&states, None, Span::call_site()));
+
+        combine(errors)?;
+
+        let builder = expand_builder(&states, |state| quote! { #state }, |event| quote! { #event });
+
+        Ok(quote! {
+            (|| -> ::stately::builder::Result<_, _, _> #builder)()
+        })
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::End { state, .. } =>
+                fmt.write_fmt(format_args!("{state} --> [*]")),
+
+            Self::Start { event: (_, _, event, _, _), state, .. } =>
+                fmt.write_fmt(format_args!("[*] --{}--> {state}", event.to_token_stream())),
+
+            Self::Transition { from, event: (_, _, event, _, _), to } =>
+                fmt.write_fmt(format_args!("{from} --{}--> {to}", event.to_token_stream())),
+        }
+    }
+}
+
+impl Parse for Dsl {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut lines = Vec::new();
+
+        while !input.is_empty() {
+            lines.push(input.parse()?);
+
+            if !input.is_empty() {
+                input.parse::<Token![;]>()?;
+            }
+        }
+
+        Ok(Self { lines })
+    }
+}
+
+impl Parse for Line {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if input.peek(token::Bracket) {
+            return Ok(Self::Start {
+                start: input.parse()?,
+                event: (input.parse()?, input.parse()?, input.parse()?, input.parse()?, input.parse()?),
+                state: input.parse()?,
+            });
+        }
+
+        let from = input.parse()?;
+        let dash = input.parse()?;
+
+        if input.peek(Token![->]) {
+            Ok(Self::End { state: from, arrow: (dash, input.parse()?), end: input.parse()? })
+        } else {
+            Ok(Self::Transition {
+                from,
+                event: (dash, input.parse()?, input.parse()?, input.parse()?, input.parse()?),
+                to: input.parse()?,
+            })
+        }
+    }
+}
+
+impl Parse for Pseudo {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let content;
+
+        Ok(Self { bracket: bracketed!(content in input), star: content.parse()? })
+    }
+}
+
+impl ToTokens for Line {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::End { state, arrow: (dash, arrow), end } =>
+                tokens.extend(quote! { #state #dash #arrow #end }),
+
+            Self::Start { start, event: (dash, second, event, third, arrow), state } =>
+                tokens.extend(quote! { #start #dash #second #event #third #arrow #state }),
+
+            Self::Transition { from, event: (dash, second, event, third, arrow), to } =>
+                tokens.extend(quote! { #from #dash #second #event #third #arrow #to }),
+        }
+    }
+}
+
+impl ToTokens for Pseudo {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.bracket.surround(tokens, |tokens| self.star.to_tokens(tokens));
+    }
+}
+
+/// Defines the state, or transition, of a line
+///
+/// # Arguments
+///
+/// * _`states`_ - the states defined by the previous lines, _in order of appearance_
+/// * _`line`_ - the line to define
+///
+/// # Errors
+///
+/// Returns an error naming the line, if it defines a duplicate state or a duplicate transition
+fn define(states: &mut Vec<Variant>, line: &Line) -> syn::Result<()> {
+    match line {
+        Line::End { state, .. } => {
+            let variant = state_of(states, state);
+
+            if variant.end.is_some() {
+                return Err(syn::Error::new_spanned(line, format!("Duplicate state `{line}`, {state} is already an end state")));
+            }
+
+            variant.end = Some(state.span());
+        }
+        Line::Start { event: (_, _, event, _, _), state, .. } => {
+            let key = event.to_token_stream().to_string();
+
+            let existing = states.iter()
+                .find(|variant| variant.start.as_ref().is_some_and(|start| start.to_token_stream().to_string() == key));
+
+            if let Some(existing) = existing {
+                return Err(syn::Error::new_spanned(
+                    line,
+                    format!("Duplicate transition `{line}`, [*] already transitions to {} on {key}", existing.ident),
+                ));
+            }
+
+            let variant = state_of(states, state);
+
+            if variant.start.is_some() {
+                return Err(syn::Error::new_spanned(line, format!("Duplicate state `{line}`, {state} is already a start state")));
+            }
+
+            variant.start = Some(event.clone());
+        }
+        Line::Transition { from, event: (_, _, event, _, _), to } => {
+            let key = event.to_token_stream().to_string();
+            let variant = state_of(states, from);
+
+            let existing = variant.transitions.iter()
+                .find(|transition| transition.event.to_token_stream().to_string() == key);
+
+            if let Some(existing) = existing {
+                return Err(syn::Error::new_spanned(
+                    line,
+                    format!("Duplicate transition `{line}`, {from} already transitions to {} on {key}", existing.state),
+                ));
+            }
+
+            variant.transitions.push(Transition { event: event.clone(), state: to.clone() });
+
+            state_of(states, to);
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds a state, adding it in order of appearance if it is new
+fn state_of<'a>(states: &'a mut Vec<Variant>, state: &Ident) -> &'a mut Variant {
+    let index = states.iter().position(|variant| variant.ident == *state)
+        .unwrap_or_else(|| {
+            states.push(Variant { end: None, fields: None, ident: state.clone(), start: None, transitions: Vec::new() });
+
+            states.len() - 1
+        });
+
+    &mut states[index]
+}
@@ -0,0 +1,51 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+use syn::spanned::Spanned;
+
+use crate::validation::combine;
+
+/// Expands an `Enumerable` implementation of a fieldless enum, which lists its
+/// variants in declaration order
+///
+/// # Arguments
+///
+/// * _`input`_ - the annotated enum
+///
+/// # Results
+///
+/// Returns the tokens of the `Enumerable` implementation
+///
+/// # Errors
+///
+/// Returns all the errors of the enum combined, each one pointing at the offending
+/// generics or variant
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(input.ident.span(), "Enumerable can only be derived for enums"));
+    };
+
+    let mut errors = Vec::new();
+
+    if !input.generics.params.is_empty() {
+        errors.push(syn::Error::new(input.generics.span(), "Enumerable can not be derived for generic enums"));
+    }
+
+    errors.extend(data.variants.iter()
+        .filter(|variant| !matches!(variant.fields, Fields::Unit))
+        .map(|variant| syn::Error::new(
+            variant.fields.span(),
+            format!("{} variant must be a unit variant", variant.ident),
+        )));
+
+    combine(errors)?;
+
+    let ident = &input.ident;
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+
+    Ok(quote! {
+        impl ::stately::Enumerable for #ident {
+            const VALUES: &'static [Self] = &[#(Self::#variants),*];
+        }
+    })
+}
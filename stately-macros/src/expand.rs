@@ -0,0 +1,130 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Path};
+
+use crate::definition::{Definition, Variant};
+
+/// Expands a validated state machine definition into a `state_machine`
+/// function of the `State` enum, which builds the definition with the
+/// `StateMachineBuilder`
+///
+/// # Arguments
+///
+/// * _`definition`_ - the validated state machine definition of an annotated `State` enum
+///
+/// # Results
+///
+/// Returns the tokens of the `state_machine` function
+pub fn expand(definition: &Definition) -> TokenStream {
+    let Definition { event, ident, vis, .. } = definition;
+
+    let builder = expand_builder(
+        &definition.variants,
+        |state| quote! { #ident::#state },
+        // events are qualified with the event type, unless they are already a path
+        |event_name| if event_name.get_ident().is_some() {
+            quote! { #event::#event_name }
+        } else {
+            quote! { #event_name }
+        },
+    );
+
+    let doc = format!(
+        "Builds the [`StateMachineDefinition`](::stately::StateMachineDefinition) of the annotated `{ident}` variants"
+    );
+
+    quote! {
+        impl #ident {
+            #[doc = #doc]
+            #[must_use]
+            #vis fn state_machine() -> ::stately::StateMachineDefinition<#ident, #event> {
+                fn build() -> ::stately::builder::Result<::stately::StateMachineDefinition<#ident, #event>, #ident, #event> {
+                    #builder
+                }
+
+                build().expect("the state machine definition is validated at compile time")
+            }
+        }
+    }
+}
+
+/// Expands the builder calls of a validated state machine definition, starting with
+/// its start states, which evaluate to the result of the `build`
+///
+/// # Arguments
+///
+/// * _`variants`_ - the states of the validated state machine definition
+/// * _`state`_ - expands a state
+/// * _`event`_ - expands an event
+///
+/// # Results
+///
+/// Returns the tokens of the builder calls
+pub fn expand_builder(
+    variants: &[Variant],
+    state: impl Fn(&Ident) -> TokenStream,
+    event: impl Fn(&Path) -> TokenStream,
+) -> TokenStream {
+    let start_states = variants.iter()
+        .filter(|variant| variant.start.is_some());
+
+    let states = variants.iter()
+        .filter(|variant| variant.is_defined() && variant.start.is_none());
+
+    let definitions = start_states.chain(states)
+        .map(|variant| expand_variant(variant, &state, &event));
+
+    quote! {
+        {
+            use ::stately::prelude::*;
+
+            StateMachineBuilder::new()
+                #(#definitions)*
+                .build()
+        }
+    }
+}
+
+/// Expands the builder calls that define a state
+fn expand_variant(
+    variant: &Variant,
+    state: impl Fn(&Ident) -> TokenStream,
+    event: impl Fn(&Path) -> TokenStream,
+) -> TokenStream {
+    let current = state(&variant.ident);
+
+    let add_state = match (&variant.start, variant.end) {
+        (Some(start), Some(_)) => {
+            let start = event(start);
+
+            quote! { .add_start_end_state(#start, #current)? }
+        }
+        (Some(start), None) => {
+            let start = event(start);
+
+            quote! { .add_start_state(#start, #current)? }
+        }
+        (None, Some(_)) => quote! { .add_end_state(#current)? },
+        (None, None) => quote! { .add_state(#current)? },
+    };
+
+    let last = variant.transitions.len().saturating_sub(1);
+
+    let transitions = variant.transitions.iter().enumerate()
+        .map(|(index, transition)| {
+            let on = event(&transition.event);
+            let next = state(&transition.state);
+
+            match (index, last) {
+                (0, 0) => quote! { .only_transition_on(#on, #next)? },
+                (index, last) if index == last => quote! { .final_transition_on(#on, #next)? },
+                _ => quote! { .transition_on(#on, #next)? },
+            }
+        });
+
+    quote! {
+        #add_state
+            .no_triggers()
+            #(#transitions)*
+    }
+}
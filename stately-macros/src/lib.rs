@@ -0,0 +1,188 @@
+//! # Stately Macros
+//!
+//! Derive macros for the [Stately] finite state machine library, _use the `derive`
+//! feature of `stately`, instead of depending on this crate directly_
+//!
+//! [Stately]: https://github.com/Nejat/stately
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+#![deny(clippy::cargo)]
+
+// doc lints
+#![deny(missing_docs)]
+#![deny(rustdoc::broken_intra_doc_links)]
+#![deny(rustdoc::private_intra_doc_links)]
+#![deny(rustdoc::invalid_codeblock_attributes)]
+#![deny(rustdoc::invalid_html_tags)]
+#![deny(rustdoc::invalid_rust_codeblocks)]
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+use crate::definition::Definition;
+use crate::dsl::Dsl;
+
+mod definition;
+mod dsl;
+mod enumerable;
+mod expand;
+mod validation;
+
+/// Derives a `state_machine` function, which returns the [`StateMachineDefinition`]
+/// defined by the attributes of the variants of a `State` enum
+///
+/// * `#[state_machine(event = Event)]` - the event type of the state machine, _defaults to `Event`_
+/// * `#[start(on = Event)]` - a start state, entered on the event from the initial state
+/// * `#[transition(Event => State, ...)]` - transitions of a state, _can be repeated_
+/// * `#[end]` - an end state, _a start state can also be an end state_
+///
+/// Variants without attributes are not part of the state machine, and the `#[default]`
+/// variant is the initial state. The definition is validated at compile time, with the same
+/// validations as `BuilderState::build`; undefined and unreachable states, duplicate
+/// transitions and states without transitions are compile errors that point at the variant.
+///
+/// ```rust
+/// use stately::prelude::*;
+/// use stately::StateMachine;
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// pub enum Event {
+///     Complete,
+///     Process,
+///     Schedule,
+/// }
+///
+/// #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, StateMachine)]
+/// pub enum State {
+///     #[default]
+///     Initial,
+///     #[start(on = Schedule)]
+///     #[transition(Process => Processing)]
+///     Scheduled,
+///     #[transition(Complete => Completed)]
+///     Processing,
+///     #[end]
+///     Completed,
+/// }
+///
+/// let mut state = State::state_machine().create();
+///
+/// assert_eq!(Some(State::Scheduled), state.start(Event::Schedule).ok());
+/// assert_eq!(Some(State::Processing), state.event(Event::Process).ok());
+/// ```
+///
+/// [`StateMachineDefinition`]: https://docs.rs/stately/latest/stately/state_machine/struct.StateMachineDefinition.html
+#[proc_macro_derive(StateMachine, attributes(end, start, state_machine, transition))]
+pub fn derive_state_machine(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    Definition::try_from(input)
+        .and_then(|definition| validation::validate(&definition).map(|()| expand::expand(&definition)))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Enumerable` for a fieldless enum, which lists every variant in declaration
+/// order, _i.e. the states, or events, checked by `BuilderState::build_strict`_
+///
+/// ```rust
+/// use stately::prelude::*;
+/// use stately::Enumerable;
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Enumerable)]
+/// pub enum Event {
+///     Complete,
+///     Schedule,
+/// }
+///
+/// #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Enumerable)]
+/// pub enum State {
+///     #[default]
+///     Initial,
+///     Scheduled,
+///     Completed,
+///     Canceled,
+/// }
+///
+/// let definition = StateMachineBuilder::new()
+///     .add_start_state(Event::Schedule, State::Scheduled).unwrap()
+///         .no_triggers()
+///         .only_transition_on(Event::Complete, State::Completed).unwrap()
+///     .add_end_state(State::Completed).unwrap()
+///         .no_triggers()
+///     .build_strict();
+///
+/// // the canceled state was forgotten
+/// assert!(definition.is_err());
+/// ```
+#[proc_macro_derive(Enumerable)]
+pub fn derive_enumerable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    enumerable::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Defines a state machine with a [Mermaid] like state diagram, that expands to the
+/// `StateMachineBuilder` calls of the definition and evaluates to the result of the `build`
+///
+/// * `[*] --Event--> State;` - a start state, entered on the event from the initial state
+/// * `State --Event--> State;` - a transition of a state
+/// * `State --> [*];` - an end state, _a start state can also be an end state_
+///
+/// States and events are expressions, _i.e. imported variants_, and states without transitions
+/// must be end states. The definition is validated at compile time, with the same validations as
+/// `BuilderState::build`; duplicate states and duplicate transitions are compile errors that name
+/// the offending line.
+///
+/// ```rust
+/// use stately::prelude::*;
+/// use stately::state_machine;
+///
+/// use Event::{Complete, Process, Schedule};
+/// use State::{Completed, Processing, Scheduled};
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+/// pub enum Event {
+///     Complete,
+///     Process,
+///     Schedule,
+/// }
+///
+/// #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash)]
+/// pub enum State {
+///     #[default]
+///     Initial,
+///     Scheduled,
+///     Processing,
+///     Completed,
+/// }
+///
+/// let definition = state_machine! {
+///     [*] --Schedule--> Scheduled;
+///     Scheduled --Process--> Processing;
+///     Processing --Complete--> Completed;
+///     Completed --> [*];
+/// };
+///
+/// let mut state = definition.expect("a valid state machine definition").create();
+///
+/// assert_eq!(Some(Scheduled), state.start(Schedule).ok());
+/// assert_eq!(Some(Processing), state.event(Process).ok());
+/// ```
+///
+/// [Mermaid]: https://mermaid.js.org/syntax/stateDiagram.html
+#[proc_macro]
+pub fn state_machine(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as Dsl)
+        .expand()
+        .unwrap_or_else(|error| {
+            let errors = error.into_compile_error();
+
+            // the definition is an expression, so all of its errors are reported in a block
+            quote::quote! { { #errors ::core::unreachable!() } }
+        })
+        .into()
+}
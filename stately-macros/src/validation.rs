@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::Ident;
+use syn::spanned::Spanned;
+
+use crate::definition::{Definition, Variant};
+
+/// Validates a state machine definition, with the same validations as
+/// `BuilderState::build`, so an invalid definition does not compile
+///
+/// # Arguments
+///
+/// * _`definition`_ - the state machine definition of an annotated `State` enum
+///
+/// # Errors
+///
+/// Returns all the validation errors of the definition combined, each one
+/// pointing at the offending variant
+pub fn validate(definition: &Definition) -> syn::Result<()> {
+    let mut errors = Vec::new();
+
+    if !definition.generics.params.is_empty() {
+        errors.push(syn::Error::new(definition.generics.span(), "StateMachine can not be derived for generic enums"));
+    }
+
+    errors.extend(validate_states(&definition.variants, definition.initial.as_ref(), definition.ident.span()));
+
+    combine(errors)
+}
+
+/// Validates the states of a state machine definition
+///
+/// # Arguments
+///
+/// * _`variants`_ - the states of the state machine definition
+/// * _`initial`_ - the initial state, _if it is known_
+/// * _`span`_ - where to report a missing start state
+///
+/// # Results
+///
+/// Returns all the validation errors of the states, each one pointing at the offending state
+pub fn validate_states(variants: &[Variant], initial: Option<&Ident>, span: Span) -> Vec<syn::Error> {
+    let mut errors = Vec::new();
+
+    if !variants.iter().any(|variant| variant.start.is_some()) {
+        errors.push(syn::Error::new(span, "A start state must be defined"));
+    }
+
+    let start_events = variants.iter()
+        .filter_map(|variant| variant.start.as_ref().map(|event| (event, &variant.ident)));
+
+    errors.extend(duplicate_transitions(start_events));
+
+    for variant in variants.iter().filter(|variant| variant.is_defined()) {
+        errors.extend(validate_variant(variant, initial));
+    }
+
+    errors.extend(undefined_states(variants));
+    errors.extend(unreachable_states(variants));
+
+    errors
+}
+
+/// Combines validation errors into a single error
+///
+/// # Arguments
+///
+/// * _`errors`_ - the validation errors
+///
+/// # Errors
+///
+/// Returns the combined errors, if there are any
+pub fn combine(errors: Vec<syn::Error>) -> syn::Result<()> {
+    errors.into_iter()
+        .reduce(|mut combined, error| {
+            combined.combine(error);
+            combined
+        })
+        .map_or(Ok(()), Err)
+}
+
+/// Finds transitions on an event already defined by a previous transition
+fn duplicate_transitions<'a>(
+    transitions: impl Iterator<Item=(&'a syn::Path, &'a Ident)>
+) -> Vec<syn::Error> {
+    let mut events = Vec::<(String, &Ident)>::new();
+    let mut errors = Vec::new();
+
+    for (event, state) in transitions {
+        let key = event.to_token_stream().to_string();
+
+        if let Some((_, existing)) = events.iter().find(|(defined, _)| *defined == key) {
+            errors.push(syn::Error::new(event.span(), format!("{key} event already transitions to {existing}")));
+        } else {
+            events.push((key, state));
+        }
+    }
+
+    errors
+}
+
+/// Finds the states that are the target of a transition but are not defined,
+/// _pointing at the variant when the `State` enum declares it_
+fn undefined_states(variants: &[Variant]) -> Vec<syn::Error> {
+    let variant = |state: &Ident| variants.iter().find(|variant| variant.ident == *state);
+    let mut reported = HashSet::new();
+
+    variants.iter()
+        .flat_map(|variant| &variant.transitions)
+        .map(|transition| &transition.state)
+        .filter(|state| !variant(state).is_some_and(Variant::is_defined))
+        .filter(|state| reported.insert(*state))
+        .map(|state| {
+            let span = variant(state).map_or_else(|| state.span(), |variant| variant.ident.span());
+
+            syn::Error::new(span, format!("Undefined state {state}, it is the target of a transition"))
+        })
+        .collect()
+}
+
+/// Finds the defined states that are not a start state, and are not the
+/// target of a transition from another state
+fn unreachable_states(variants: &[Variant]) -> Vec<syn::Error> {
+    variants.iter()
+        .filter(|variant| variant.is_defined() && variant.start.is_none())
+        .filter(|variant| !variants.iter()
+            .filter(|from| from.ident != variant.ident)
+            .flat_map(|from| &from.transitions)
+            .any(|transition| transition.state == variant.ident))
+        .map(|variant| syn::Error::new(
+            variant.ident.span(),
+            format!("Unreachable state {}, there are no transitions to the state", variant.ident),
+        ))
+        .collect()
+}
+
+/// Validates the attributes of a defined state
+fn validate_variant(variant: &Variant, initial: Option<&Ident>) -> Vec<syn::Error> {
+    let mut errors = Vec::new();
+
+    if let Some(fields) = variant.fields {
+        errors.push(syn::Error::new(fields, format!("{} state must be a unit variant", variant.ident)));
+    }
+
+    if initial == Some(&variant.ident) {
+        errors.push(syn::Error::new(variant.ident.span(), "Initial state can not be explicitly defined"));
+    }
+
+    match (variant.end, variant.transitions.first()) {
+        (Some(_), Some(transition)) =>
+            errors.push(syn::Error::new(
+                transition.event.span(),
+                format!("{} end state can not define transitions", variant.ident),
+            )),
+        (None, None) =>
+            errors.push(syn::Error::new(
+                variant.ident.span(),
+                format!("{} state must define transitions, or be an end state", variant.ident),
+            )),
+        _ => {}
+    }
+
+    errors.extend(duplicate_transitions(
+        variant.transitions.iter().map(|transition| (&transition.event, &transition.state))
+    ));
+
+    errors
+}